@@ -0,0 +1,72 @@
+//! Derive macros for the `rust_units` crate.
+//!
+//! They are re-exported by `rust_units` with the `derive` feature, and should be used from there.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, spanned::Spanned, Data, DeriveInput, Fields, Index, Type};
+
+/// Derives `rust_units::si_system::runtime::CheckDimensions`.
+///
+/// Each field annotated with `#[dimension(D)]`, where `D` implements `ToRuntimeDimension`,
+/// is checked against the dimension `D`. See the documentation of the trait for an example.
+#[proc_macro_derive(CheckDimensions, attributes(dimension))]
+pub fn derive_check_dimensions(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    check_dimensions(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn check_dimensions(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new(input.span(), "CheckDimensions can only be derived for structs"));
+    };
+
+    let mut checks = Vec::new();
+    let fields: Vec<_> = match &data.fields {
+        Fields::Named(fields) => fields.named.iter().collect(),
+        Fields::Unnamed(fields) => fields.unnamed.iter().collect(),
+        Fields::Unit => Vec::new(),
+    };
+    for (i, field) in fields.into_iter().enumerate() {
+        for attribute in field.attrs.iter().filter(|a| a.path().is_ident("dimension")) {
+            let dimension: Type = attribute.parse_args()?;
+            let (member, name) = match &field.ident {
+                Some(ident) => (quote!(#ident), ident.to_string()),
+                None => {
+                    let index = Index::from(i);
+                    (quote!(#index), i.to_string())
+                }
+            };
+            checks.push(quote! {
+                ::rust_units::si_system::runtime::FieldDimensionError::check(
+                    &mut errors,
+                    #name,
+                    &self.#member,
+                    <#dimension as ::rust_units::si_system::runtime::ToRuntimeDimension>::runtime_dimension(),
+                );
+            });
+        }
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::rust_units::si_system::runtime::CheckDimensions for #name #ty_generics #where_clause {
+            fn check_dimensions(
+                &self,
+            ) -> ::core::result::Result<(), ::std::vec::Vec<::rust_units::si_system::runtime::FieldDimensionError>> {
+                #[allow(unused_mut)]
+                let mut errors = ::std::vec::Vec::new();
+                #(#checks)*
+                if errors.is_empty() {
+                    ::core::result::Result::Ok(())
+                } else {
+                    ::core::result::Result::Err(errors)
+                }
+            }
+        }
+    })
+}
@@ -6,4 +6,6 @@ mod units;
 
 pub use dimension::*;
 pub use quantity::*;
-pub use units::*;
\ No newline at end of file
+pub use units::*;
+#[cfg(feature = "testing")]
+pub mod testing;
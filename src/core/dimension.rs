@@ -14,6 +14,29 @@ use crate::{Quantity, Unit};
 ///
 /// **Note**: If you implement this trait yourself, make sure to implement the operation traits ([`Add`](std::ops::Add),[`Mul`](std::ops::Mul),...)
 /// in a coherent way, such that the operations are defined if and only if they make sense.
+///
+/// This trait does not carry the exponents of the dimension: they are handled by the dimension system,
+/// through the operation traits. For example, the [`SIDimension`](crate::si_system::SIDimension)s
+/// add their type-level exponents when multiplied, and can only be added if their exponents are the same:
+///
+/// ```
+/// use rust_units::{Quantity, Dimension};
+/// use rust_units::si_system::dimensions::*;
+/// use extended_typenum::op;
+///
+/// let product: Quantity<f64, op!(Length * Time)> = Length::from_work(2.0) * Time::from_work(3.0);
+/// let speed: Quantity<f64, Speed> = Length::from_work(6.0) / Time::from_work(3.0);
+///
+/// assert_eq!(product.get_work(), 6.0);
+/// assert_eq!(speed.get_work(), 2.0);
+/// ```
+///
+/// ```compile_fail
+/// use rust_units::Dimension;
+/// use rust_units::si_system::dimensions::*;
+///
+/// let _ = Length::from_work(2.0) + Time::from_work(3.0);
+/// ```
 pub trait Dimension {
     /// Creates a new [`Quantity`](Quantity) from its work (default) [`Unit`].
     fn from_work<T>(value: T) -> Quantity<T, Self>
@@ -32,6 +55,16 @@ pub trait Dimension {
     }
 }
 
+/// Trait for dimensions whose square root is also a dimension of their system.
+///
+/// It is used by [`Quantity::sqrt`]. For example, the square root of an area is a length,
+/// but a length has no square root (unlike [`FloatSqrt`](crate::float::FloatSqrt),
+/// which accepts any dimension that can be raised to the power ½).
+pub trait DimSqrt: Dimension {
+    /// The square root of the dimension.
+    type Output: Dimension;
+}
+
 /// Some marker traits used to enable generic implementations of traits on the [`Quantity`] type.
 pub mod markers {
     /// Marker trait used to indicate that a dimension type represents a dimensionless number.
@@ -6,7 +6,12 @@ use num_traits::{ConstOne, ConstZero, Inv, MulAdd, MulAddAssign, One, Pow, Zero}
 
 use super::*;
 
-use std::{fmt::Display, marker::PhantomData, ops::*};
+use core::{
+    fmt::Display,
+    iter::{Product, Sum},
+    marker::PhantomData,
+    ops::*,
+};
 
 /// Dimensioned value.
 ///
@@ -43,9 +48,23 @@ use std::{fmt::Display, marker::PhantomData, ops::*};
 ///
 /// If you want to use a quantity in other operations, you need to implement it yourself.
 ///
+/// The comparison and hashing traits ([`PartialEq`], [`Eq`], [`PartialOrd`], [`Ord`] and [`Hash`](std::hash::Hash))
+/// only use the value, as the dimension is part of the type. For example, quantities can be stored in a set:
+///
+/// ```
+/// use rust_units::{Quantity, Dimension, si_system::dimensions::Length};
+/// use std::collections::HashSet;
+///
+/// let lengths: HashSet<Quantity<i64, Length>> = [3, 5, 3].map(Length::from_work).into();
+///
+/// assert_eq!(lengths.len(), 2);
+/// assert!(lengths.contains(&Length::from_work(5)));
+/// ```
+///
 /// Similarly to Rust's [`Option`] enum, this struct also provides functions to help with references management such as
 /// [`as_ref`](Quantity::as_ref), [`as_mut`](Quantity::as_mut), [`as_deref`](Quantity::as_deref), [`as_deref_mut`](Quantity::as_deref_mut).
 #[derive_where(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash; T)]
+#[repr(transparent)]
 pub struct Quantity<T, D: Dimension> {
     value: T,
     dimension: PhantomData<D>,
@@ -53,6 +72,19 @@ pub struct Quantity<T, D: Dimension> {
 
 impl<T, D: Dimension> Quantity<T, D> {
     /// Creates a new quantity from its work (default) [`unit`](super::units::Unit).
+    ///
+    /// As it is a `const fn`, it can be used to declare constant quantities:
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Unit};
+    /// use rust_units::si_system::{dimensions::Acceleration, units::*};
+    ///
+    /// const GRAVITY: Quantity<f64, Acceleration> = Quantity::from_work(9.80665);
+    /// const GRAVITY_REF: &f64 = GRAVITY.get_ref_work();
+    ///
+    /// assert_eq!(GRAVITY, STANDARD_GRAVITY.build(1.0));
+    /// assert_eq!(*GRAVITY_REF, 9.80665);
+    /// ```
     pub const fn from_work(value: T) -> Self {
         Self {
             value,
@@ -70,23 +102,329 @@ impl<T, D: Dimension> Quantity<T, D> {
         unit.get(self)
     }
 
+    /// Returns the value expressed in the given unit. Same as [`get_in`](Quantity::get_in).
+    ///
+    /// See [`convert`] to convert a plain number from one unit to another.
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Unit};
+    /// use rust_units::si_system::units::*;
+    ///
+    /// assert_eq!(METER.build(1000.0).value_in(&KILOMETER), 1.0);
+    /// ```
+    pub fn value_in<U: Unit<T, Dimension = D>>(self, unit: &U) -> T {
+        unit.get(self)
+    }
+
+    /// Returns the value in the given unit along with the unit's symbol.
+    ///
+    /// Useful to write a quantity in two columns of a CSV file.
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Unit};
+    /// use rust_units::si_system::units::*;
+    ///
+    /// let kilojoule = JOULE.set_kilo_prefix().redefine_as("kJ");
+    /// let row = [JOULE.build(1500.0), JOULE.build(250.0)];
+    ///
+    /// let cells = row.map(|energy| energy.csv_cell(&kilojoule));
+    ///
+    /// assert_eq!(cells, [(1.5, "kJ"), (0.25, "kJ")]);
+    /// ```
+    pub fn csv_cell<U: Unit<T, Dimension = D> + UnitSymbol>(self, unit: &U) -> (T, &'static str) {
+        (unit.get(self), unit.symbol())
+    }
+
+    /// Returns how many times `reference` this quantity is, as a plain number.
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Unit};
+    /// use rust_units::si_system::units::*;
+    ///
+    /// let marathon = METER.set_kilo_prefix().build(42.195);
+    /// let track_lap = METER.build(400.0);
+    ///
+    /// assert!((marathon.relative_to(track_lap) - 105.4875).abs() < 1e-9);
+    /// ```
+    pub fn relative_to(self, reference: Self) -> T
+    where
+        T: Div<Output = T>,
+    {
+        self.get_work() / reference.get_work()
+    }
+
+    /// Returns the fraction of `full_scale` this quantity represents, clamped to `[0, 1]`.
+    ///
+    /// This is the fill ratio of a gauge. A `NaN` ratio gives 0.
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Unit};
+    /// use rust_units::si_system::units::*;
+    ///
+    /// let full_scale = PASCAL.set_kilo_prefix().build(400.0);
+    ///
+    /// assert_eq!(PASCAL.set_kilo_prefix().build(100.0).fraction_of(full_scale), 0.25);
+    /// assert_eq!(PASCAL.set_kilo_prefix().build(500.0).fraction_of(full_scale), 1.0);
+    /// assert_eq!(PASCAL.build(-20.0).fraction_of(full_scale), 0.0);
+    /// ```
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub fn fraction_of(self, full_scale: Self) -> T
+    where
+        T: num_traits::Float,
+    {
+        (self.get_work() / full_scale.get_work()).max(T::zero()).min(T::one())
+    }
+
+    /// Returns the smallest of the two quantities.
+    ///
+    /// As for [`f64::min`], a value that can not be compared with itself (such as `NaN`) is ignored.
+    /// It takes precedence over [`Float::min`](float::Float::min) and [`Ord::min`], with the same result.
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Unit};
+    /// use rust_units::si_system::units::*;
+    ///
+    /// assert_eq!(METER.build(3.0).min(CENTIMETER.build(50.0)), METER.build(0.5));
+    /// assert_eq!(METER.build(f64::NAN).min(METER.build(1.0)), METER.build(1.0));
+    /// ```
+    pub fn min(self, other: Self) -> Self
+    where
+        T: PartialOrd,
+    {
+        if other < self || self.partial_cmp(&self).is_none() {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Returns the largest of the two quantities.
+    ///
+    /// As for [`f64::max`], a value that can not be compared with itself (such as `NaN`) is ignored.
+    /// It takes precedence over [`Float::max`](float::Float::max) and [`Ord::max`], with the same result.
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Unit};
+    /// use rust_units::si_system::units::*;
+    ///
+    /// assert_eq!(METER.build(3.0).max(CENTIMETER.build(50.0)), METER.build(3.0));
+    /// assert_eq!(METER.build(1.0).max(METER.build(f64::NAN)), METER.build(1.0));
+    /// ```
+    pub fn max(self, other: Self) -> Self
+    where
+        T: PartialOrd,
+    {
+        if other > self || self.partial_cmp(&self).is_none() {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Restricts the quantity to `[min, max]`.
+    ///
+    /// A value that can not be compared (such as `NaN`) is returned as is.
+    /// It takes precedence over [`Float::clamp`](float::Float::clamp) and [`Ord::clamp`], with the same result.
+    ///
+    /// **Panics** in debug mode if `!(min <= max)`.
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Unit};
+    /// use rust_units::si_system::units::*;
+    ///
+    /// assert_eq!(METER.build(3.0).clamp(METER.build(1.0), METER.build(2.0)), METER.build(2.0));
+    /// assert_eq!(METER.build(1.5).clamp(METER.build(1.0), METER.build(2.0)), METER.build(1.5));
+    /// ```
+    pub fn clamp(self, min: Self, max: Self) -> Self
+    where
+        T: PartialOrd,
+    {
+        debug_assert!(min <= max, "the minimum of a clamp can not be greater than its maximum");
+        self.clamp_reported(min, max).0
+    }
+
+    /// Restricts the quantity to `[min, max]`, and reports whether it was out of this interval.
+    ///
+    /// Useful to log saturation events. A value that can not be compared (such as `NaN`) is returned as is.
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Unit};
+    /// use rust_units::si_system::units::*;
+    ///
+    /// let (min, max) = (VOLT.build(0.0), VOLT.build(5.0));
+    ///
+    /// assert_eq!(VOLT.build(3.3).clamp_reported(min, max), (VOLT.build(3.3), false));
+    /// assert_eq!(VOLT.build(5.0).clamp_reported(min, max), (VOLT.build(5.0), false));
+    /// assert_eq!(VOLT.build(12.0).clamp_reported(min, max), (VOLT.build(5.0), true));
+    /// assert_eq!(VOLT.build(-1.0).clamp_reported(min, max), (VOLT.build(0.0), true));
+    /// ```
+    pub fn clamp_reported(self, min: Self, max: Self) -> (Self, bool)
+    where
+        T: PartialOrd,
+    {
+        if self < min {
+            (min, true)
+        } else if self > max {
+            (max, true)
+        } else {
+            (self, false)
+        }
+    }
+
+    /// Returns by how many `tolerance`s this quantity deviates from `nominal`: `(self - nominal) / tolerance`.
+    ///
+    /// The result is a plain number, whose absolute value is at most 1 if the quantity is within tolerance.
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Unit};
+    /// use rust_units::si_system::units::*;
+    ///
+    /// let millimeter = METER.set_milli_prefix();
+    /// let nominal = millimeter.build(10.0);
+    /// let tolerance = millimeter.build(0.5);
+    ///
+    /// let in_band = millimeter.build(9.75).deviation_in_tolerances(nominal, tolerance);
+    /// let out_of_band = millimeter.build(11.5).deviation_in_tolerances(nominal, tolerance);
+    ///
+    /// assert!((in_band + 0.5).abs() < 1e-9);
+    /// assert!((out_of_band - 3.0).abs() < 1e-9);
+    /// ```
+    pub fn deviation_in_tolerances(self, nominal: Self, tolerance: Self) -> T
+    where
+        T: Sub<Output = T> + Div<Output = T>,
+    {
+        (self.get_work() - nominal.get_work()) / tolerance.get_work()
+    }
+
+    /// Returns the relative difference from `reference` in percent, `(self - reference) / reference * 100`.
+    ///
+    /// As both quantities have the same dimension, the result is a plain number.
+    ///
+    /// If `reference` is zero, the result follows the float division rules:
+    /// it is infinite (or `NaN` if `self` is also zero).
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Unit};
+    /// use rust_units::si_system::units::*;
+    ///
+    /// let before = PASCAL.build(100_000.0);
+    /// let after = PASCAL.build(150_000.0);
+    ///
+    /// assert_eq!(after.percent_difference_from(before), 50.0);
+    /// assert_eq!(PASCAL.build(75_000.0).percent_difference_from(before), -25.0);
+    /// assert!(after.percent_difference_from(PASCAL.build(0.0)).is_infinite());
+    /// ```
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub fn percent_difference_from(self, reference: Self) -> T
+    where
+        T: num_traits::Float,
+    {
+        let reference = reference.get_work();
+        (self.get_work() - reference) / reference * T::from(100).unwrap()
+    }
+
+    /// Clamps the magnitude of the quantity to `max`, keeping its sign.
+    ///
+    /// Equivalent to `copysign(min(|self|, max), self)`. `max` should be positive.
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Dimension, si_system::dimensions::Velocity};
+    ///
+    /// let max = Velocity::from_work(5.0);
+    ///
+    /// assert_eq!(Velocity::from_work(8.0).clamp_abs(max), Velocity::from_work(5.0));
+    /// assert_eq!(Velocity::from_work(-8.0).clamp_abs(max), Velocity::from_work(-5.0));
+    /// assert_eq!(Velocity::from_work(-3.0).clamp_abs(max), Velocity::from_work(-3.0));
+    /// ```
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub fn clamp_abs(self, max: Self) -> Self
+    where
+        T: num_traits::Float,
+    {
+        let value = self.get_work();
+        Self::from_work(value.abs().min(max.get_work()).copysign(value))
+    }
+
+    /// Returns a quantity with the magnitude of `self` and the sign of `sign`, keeping the dimension of `self`.
+    ///
+    /// `sign` can be a plain number or a quantity of any dimension (see [`FloatCopysign`](float::FloatCopysign)).
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Unit};
+    /// use rust_units::si_system::units::*;
+    ///
+    /// let force = NEWTON.build(3.0);
+    ///
+    /// assert_eq!(force.copysign(-METER.build(0.5)), NEWTON.build(-3.0));
+    /// assert_eq!((-force).copysign(SECOND.build(2.0)), NEWTON.build(3.0));
+    /// assert_eq!(force.copysign(-1.0), NEWTON.build(-3.0));
+    /// ```
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub fn copysign<S>(self, sign: S) -> Self
+    where
+        Self: float::FloatCopysign<S>,
+    {
+        float::FloatCopysign::copysign(self, sign)
+    }
+
     /// Returns the numerical value of the quantity in the work (default) [`unit`](super::units::Unit).
     pub fn get_work(self) -> T {
         self.value
     }
 
     /// Returns a reference to the numerical value of the quantity in the work (default) [`unit`](super::units::Unit).
-    pub fn get_ref_work(&self) -> &T {
+    pub const fn get_ref_work(&self) -> &T {
         &self.value
     }
 
+    /// Returns a copy of the numerical value of the quantity in the work (default) [`unit`](super::units::Unit).
+    ///
+    /// Unlike [`get_work`](Quantity::get_work), it can be used in constant contexts.
+    ///
+    /// ```
+    /// use rust_units::Quantity;
+    /// use rust_units::si_system::dimensions::Length;
+    ///
+    /// const TABLE: [Quantity<f64, Length>; 3] = [
+    ///     Quantity::from_work(0.5),
+    ///     Quantity::from_work(1.0),
+    ///     Quantity::from_work(2.0),
+    /// ];
+    /// const LAST: f64 = TABLE[2].get_work_copy();
+    ///
+    /// assert_eq!(LAST, 2.0);
+    /// ```
+    pub const fn get_work_copy(&self) -> T
+    where
+        T: Copy,
+    {
+        self.value
+    }
+
     /// Returns a mutable reference to the numerical value of the quantity in the work (default) [`unit`](super::units::Unit).
     pub fn get_mut_work(&mut self) -> &mut T {
         &mut self.value
     }
 
+    /// Negates the quantity in place, keeping its dimension.
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Dimension, si_system::dimensions::Velocity};
+    ///
+    /// let mut v = Velocity::from_work(3.0);
+    /// v.neg_assign();
+    ///
+    /// assert_eq!(v, Velocity::from_work(-3.0));
+    /// ```
+    pub fn neg_assign(&mut self)
+    where
+        T: Neg<Output = T> + Copy,
+    {
+        self.value = -self.value
+    }
+
     /// Converts a `&Quantity<T, D>` into a `Quantity<&T, D>`
-    pub fn as_ref(&self) -> Quantity<&T, D> {
+    pub const fn as_ref(&self) -> Quantity<&T, D> {
         Quantity {
             value: &self.value,
             dimension: PhantomData,
@@ -147,6 +485,209 @@ impl<T, D: Dimension> Quantity<T, D> {
     {
         Quantity::from_work(self.get_work().as_())
     }
+
+    /// Converts the inner value with [`num_traits::NumCast`], clamping it to the bounds of `U` if it is out of range.
+    ///
+    /// For a float target, the bounds are the largest finite values, so infinities are clamped too.
+    /// A value that can not be compared (such as `NaN`) gives zero.
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Unit};
+    /// use rust_units::si_system::units::*;
+    ///
+    /// assert_eq!(METER.build(1e12).saturating_cast::<i32>(), METER.build(i32::MAX));
+    /// assert_eq!(METER.build(-1e12).saturating_cast::<i32>(), METER.build(i32::MIN));
+    /// assert_eq!(METER.build(42.7).saturating_cast::<i32>(), METER.build(42));
+    /// assert_eq!(METER.build(f64::NAN).saturating_cast::<i32>(), METER.build(0));
+    /// assert_eq!(METER.build(-5i64).saturating_cast::<u8>(), METER.build(0u8));
+    ///
+    /// assert_eq!(METER.build(1e300).saturating_cast::<f32>(), METER.build(f32::MAX));
+    /// assert_eq!(METER.build(f64::NEG_INFINITY).saturating_cast::<f32>(), METER.build(f32::MIN));
+    /// assert_eq!(METER.build(f64::NAN).saturating_cast::<f32>(), METER.build(0.0f32));
+    /// assert_eq!(METER.build(0.5).saturating_cast::<f32>(), METER.build(0.5f32));
+    /// ```
+    pub fn saturating_cast<U>(self) -> Quantity<U, D>
+    where
+        T: num_traits::ToPrimitive + Zero + PartialOrd,
+        U: num_traits::NumCast + num_traits::Bounded + Zero,
+    {
+        let value = self.get_work();
+        if value.partial_cmp(&value).is_none() {
+            return Quantity::from_work(U::zero());
+        }
+        // NumCast does not fail between floats (1e300 gives an infinite f32), so the bounds are checked first.
+        // They are compared as f64, whose rounding of the largest integers only affects values clamped to them anyway.
+        let (max, min) = (U::max_value(), U::min_value());
+        if let (Some(x), Some(max_x), Some(min_x)) = (value.to_f64(), max.to_f64(), min.to_f64()) {
+            if x >= max_x {
+                return Quantity::from_work(max);
+            }
+            if x <= min_x {
+                return Quantity::from_work(min);
+            }
+        }
+        let (positive, negative) = (value > T::zero(), value < T::zero());
+        Quantity::from_work(match U::from(value) {
+            Some(value) => value,
+            None if positive => max,
+            None if negative => min,
+            None => U::zero(),
+        })
+    }
+
+    /// Applies a function to the inner value, keeping the dimension.
+    ///
+    /// The function is applied to the value in work unit, so it must not depend on the unit.
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Dimension, si_system::dimensions::Length};
+    ///
+    /// let length = Length::from_work(1.5f32);
+    ///
+    /// assert_eq!(length.map(|x| x * 2.0), Length::from_work(3.0f32));
+    /// assert_eq!(length.map(f64::from), Length::from_work(1.5f64));
+    /// ```
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> Quantity<U, D> {
+        Quantity::from_work(f(self.get_work()))
+    }
+
+    /// Zips the inner values of two quantities of the same dimension into a quantity holding a pair.
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Dimension, si_system::dimensions::Length};
+    ///
+    /// let zipped = Length::from_work(1.0).zip(Length::from_work(vec![2.0, 3.0]));
+    ///
+    /// assert_eq!(zipped, Length::from_work((1.0, vec![2.0, 3.0])));
+    /// ```
+    pub fn zip<U>(self, other: Quantity<U, D>) -> Quantity<(T, U), D> {
+        Quantity::from_work((self.get_work(), other.get_work()))
+    }
+}
+
+impl<T: num_traits::Signed, D: Dimension> Quantity<T, D> {
+    /// Returns the absolute value of the quantity, keeping its dimension.
+    ///
+    /// Unlike [`Float::abs`](float::Float::abs), it is also available for signed integers.
+    /// See [`FloatCopysign`](float::FloatCopysign) to copy the sign of another quantity, whatever its dimension.
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Unit, Dimension};
+    /// use rust_units::si_system::{dimensions::Length, units::*};
+    ///
+    /// assert_eq!((-METER.build(3.0)).abs(), METER.build(3.0));
+    /// assert_eq!(Length::from_work(-3i32).abs(), Length::from_work(3));
+    /// ```
+    pub fn abs(self) -> Self {
+        Self::from_work(self.get_work().abs())
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<T: num_traits::Float, D: Dimension> Quantity<T, D> {
+    /// Raises the quantity to the integer power `N`, known at compile time.
+    ///
+    /// The dimension of the result is `D` to the power `N`, computed with [`Pow`].
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Unit, Dimension};
+    /// use rust_units::si_system::{dimensions::*, units::*};
+    /// use extended_typenum::{N1, P2, P3};
+    ///
+    /// let length = METER.build(3.0);
+    ///
+    /// let area: Quantity<f64, Area> = length.powi::<P2>();
+    /// assert_eq!(area, length * length);
+    ///
+    /// let volume: Quantity<f64, Volume> = length.powi::<P3>();
+    /// assert_eq!(volume, Volume::from_work(27.0));
+    ///
+    /// assert_eq!(SECOND.build(4.0).powi::<N1>(), HERTZ.build(0.25));
+    /// ```
+    pub fn powi<N: extended_typenum::Integer>(self) -> Quantity<T, <D as Pow<N>>::Output>
+    where
+        D: Pow<N>,
+        <D as Pow<N>>::Output: Dimension,
+    {
+        Quantity::from_work(self.get_work().powi(N::I32))
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<T: num_traits::Float, D> Quantity<T, D>
+where
+    D: Dimension + Inv<Output: Dimension>,
+{
+    /// Takes the reciprocal (inverse) of the quantity, `1/x`. Its dimension is inverted with [`Inv`].
+    ///
+    /// Same as [`FloatRecip::recip`](float::FloatRecip::recip), without importing the trait.
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Unit};
+    /// use rust_units::si_system::{dimensions::Frequency, units::*};
+    ///
+    /// let period = SECOND.build(2.0);
+    ///
+    /// let frequency: Quantity<f64, Frequency> = period.recip();
+    /// assert_eq!(frequency, HERTZ.build(0.5));
+    ///
+    /// let q = SECOND.build(3.0);
+    /// assert!((q.recip().recip() - q).get_work().abs() < f64::EPSILON);
+    /// ```
+    pub fn recip(self) -> Quantity<T, D::Output> {
+        Quantity::from_work(self.get_work().recip())
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<T: num_traits::Float, D: DimSqrt> Quantity<T, D> {
+    /// Takes the square root of the quantity. Returns `NaN` if the value is negative.
+    ///
+    /// Only available if the dimension has a square root (see [`DimSqrt`]): for the SI system, all its exponents must be even.
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Dimension};
+    /// use rust_units::si_system::dimensions::*;
+    ///
+    /// let side: Quantity<f64, Length> = Area::from_work(16.0).sqrt();
+    /// assert_eq!(side, Length::from_work(4.0));
+    ///
+    /// let velocity = Velocity::from_work(3.0);
+    /// assert_eq!((velocity * velocity).sqrt(), velocity);
+    ///
+    /// // Kinetic energy to speed.
+    /// let speed: Quantity<f64, Velocity> = (Energy::from_work(50.0) / Mass::from_work(1.0)).sqrt();
+    /// assert_eq!(speed, Velocity::from_work(50f64.sqrt()));
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use rust_units::Dimension;
+    /// use rust_units::si_system::dimensions::*;
+    ///
+    /// let _ = Length::from_work(4.0).sqrt();
+    /// ```
+    pub fn sqrt(self) -> Quantity<T, D::Output> {
+        Quantity::from_work(self.get_work().sqrt())
+    }
+}
+
+impl<D: Dimension> Quantity<f64, D> {
+    /// Returns the value in work unit, narrowed to a [`f32`] (for example to upload it to a GPU).
+    ///
+    /// The narrowing loses precision (about 7 significant digits are kept),
+    /// and values out of the range of [`f32`] become infinite.
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Unit};
+    /// use rust_units::si_system::units::*;
+    ///
+    /// assert_eq!(METER.build(1.5).to_f32_work(), 1.5f32);
+    /// assert_eq!(METER.build(0.1).to_f32_work(), 0.1f32);
+    /// assert_eq!(METER.build(1e300).to_f32_work(), f32::INFINITY);
+    /// ```
+    pub fn to_f32_work(self) -> f32 {
+        self.get_work() as f32
+    }
 }
 
 impl<Tl, Tr, Dl: Dimension, Dr: Dimension> Add<Quantity<Tr, Dr>> for Quantity<Tl, Dl>
@@ -341,6 +882,151 @@ where
     const ZERO: Self = Self::from_work(<T as ConstZero>::ZERO);
 }
 
+impl<T: ConstZero, D: Dimension> Quantity<T, D> {
+    /// The quantity whose value is zero, whatever the unit.
+    ///
+    /// Unlike [`ConstZero::ZERO`], it does not require the trait to be imported.
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Dimension, si_system::dimensions::Velocity};
+    ///
+    /// let velocity = Velocity::from_work(-2.0);
+    ///
+    /// assert!(velocity < Quantity::ZERO);
+    /// assert!(-velocity > Quantity::ZERO);
+    /// assert_eq!(Quantity::<f64, Velocity>::ZERO.get_work(), 0.0);
+    /// ```
+    pub const ZERO: Self = Self::from_work(<T as ConstZero>::ZERO);
+}
+
+impl<T: ConstOne, D: Dimension + markers::DimensionLess> Quantity<T, D> {
+    /// The dimensionless quantity whose value is one.
+    ///
+    /// ```
+    /// use rust_units::{Quantity, si_system::{dimless, SIDimensionless}};
+    ///
+    /// assert_eq!(Quantity::<f64, SIDimensionless>::ONE, dimless(1.0));
+    /// assert!(dimless(0.5) < Quantity::ONE);
+    /// ```
+    pub const ONE: Self = Self::from_work(<T as ConstOne>::ONE);
+}
+
+impl<T, D: Dimension + markers::DimensionLess> Quantity<T, D> {
+    /// Returns the plain number held by a dimensionless quantity.
+    ///
+    /// The [`From`] conversions between dimensionless quantities and their number are also implemented
+    /// (from the quantity only for the primitive numbers).
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Unit};
+    /// use rust_units::si_system::{units::*, SIDimensionless};
+    ///
+    /// assert_eq!((METER.build(6.0) / METER.build(2.0)).into_scalar(), 3.0);
+    ///
+    /// let ratio: Quantity<f64, SIDimensionless> = 0.25.into();
+    /// assert_eq!(f64::from(ratio), 0.25);
+    /// ```
+    pub fn into_scalar(self) -> T {
+        self.value
+    }
+}
+
+impl<T, D: Dimension + markers::DimensionLess> From<T> for Quantity<T, D> {
+    fn from(value: T) -> Self {
+        Self::from_work(value)
+    }
+}
+
+macro_rules! impl_from_dimensionless {
+    ($($T:ty)*) => {
+        $(impl<D: Dimension + markers::DimensionLess> From<Quantity<$T, D>> for $T {
+            fn from(quantity: Quantity<$T, D>) -> Self {
+                quantity.into_scalar()
+            }
+        })*
+    };
+}
+
+impl_from_dimensionless!(f32 f64 i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+
+/// Multiplies dimensionless quantities, starting from one.
+///
+/// The product of quantities with a dimension is not implemented, as its dimension would depend on the number of factors.
+///
+/// ```
+/// use rust_units::Quantity;
+/// use rust_units::si_system::{dimless, SIDimensionless};
+///
+/// let ratios = [dimless(0.5), dimless(4.0), dimless(1.5)];
+///
+/// let gain: Quantity<f64, SIDimensionless> = ratios.into_iter().product();
+/// assert_eq!(gain, dimless(0.5 * 4.0 * 1.5));
+/// assert_eq!(ratios.iter().product::<Quantity<f64, SIDimensionless>>(), gain);
+/// ```
+impl<T, D> Product for Quantity<T, D>
+where
+    T: Mul<Output = T> + One,
+    D: Dimension + markers::DimensionLess,
+{
+    fn product<I: Iterator<Item = Quantity<T, D>>>(iter: I) -> Self {
+        Self::from_work(iter.fold(T::one(), |acc, q| acc * q.get_work()))
+    }
+}
+
+/// Multiplies dimensionless quantities given by reference, starting from one.
+impl<'a, T, D> Product<&'a Quantity<T, D>> for Quantity<T, D>
+where
+    T: Mul<Output = T> + Copy + One + 'a,
+    D: Dimension + markers::DimensionLess + 'a,
+{
+    fn product<I: Iterator<Item = &'a Quantity<T, D>>>(iter: I) -> Self {
+        Self::from_work(iter.fold(T::one(), |acc, q| acc * *q.get_ref_work()))
+    }
+}
+
+/// Sums quantities of the same dimension, starting from zero.
+///
+/// ```
+/// use rust_units::{Quantity, Unit};
+/// use rust_units::si_system::{dimensions::Length, units::*};
+///
+/// let total: Quantity<f64, Length> = vec![METER.build(1.0), METER.build(2.0)].into_iter().sum();
+///
+/// assert_eq!(total, METER.build(3.0));
+/// assert_eq!(Vec::new().into_iter().sum::<Quantity<f64, Length>>(), METER.build(0.0));
+/// ```
+impl<T, D: Dimension> Sum for Quantity<T, D>
+where
+    T: Add<Output = T> + Zero,
+    D: Add<D, Output = D>,
+{
+    fn sum<I: Iterator<Item = Quantity<T, D>>>(iter: I) -> Self {
+        Self::from_work(iter.fold(T::zero(), |acc, q| acc + q.get_work()))
+    }
+}
+
+/// Sums quantities given by reference, without cloning them.
+///
+/// The sum starts from zero, and all the summed quantities have the same dimension.
+///
+/// ```
+/// use rust_units::{Quantity, Dimension, si_system::dimensions::Energy};
+///
+/// let energies = [Energy::from_work(1.5), Energy::from_work(2.0), Energy::from_work(0.5)];
+/// let total: Quantity<f64, Energy> = energies.iter().sum();
+///
+/// assert_eq!(total, Energy::from_work(4.0));
+/// ```
+impl<'a, T, D: Dimension> Sum<&'a Quantity<T, D>> for Quantity<T, D>
+where
+    T: Add<Output = T> + Copy + Zero + 'a,
+    D: Add<D, Output = D> + 'a,
+{
+    fn sum<I: Iterator<Item = &'a Quantity<T, D>>>(iter: I) -> Self {
+        Self::from_work(iter.fold(T::zero(), |acc, q| acc + *q.get_ref_work()))
+    }
+}
+
 impl<T, D: Dimension> Inv for Quantity<T, D>
 where
     T: Inv,
@@ -393,16 +1079,70 @@ where
     }
 }
 
+/// Gives access to the value in work unit, for generic code taking an `AsRef<T>`.
+///
+/// Note that the inherent [`as_ref`](Quantity::as_ref) method, which returns a `Quantity<&T, D>`, takes precedence
+/// in method call syntax. Use `AsRef::<T>::as_ref` to call this one explicitly.
+///
+/// [`Borrow`](std::borrow::Borrow) is deliberately not implemented, as it would let a collection of quantities
+/// be queried with a plain number, silently ignoring the dimension.
+///
+/// ```
+/// use rust_units::{Quantity, Unit};
+/// use rust_units::si_system::units::*;
+///
+/// fn work_value(value: impl AsRef<f64>) -> f64 {
+///     *value.as_ref()
+/// }
+///
+/// let length = METER.set_kilo_prefix().build(1.5);
+///
+/// assert_eq!(work_value(length), 1500.0);
+/// assert_eq!(*AsRef::<f64>::as_ref(&length), 1500.0);
+/// ```
+impl<T, D: Dimension> AsRef<T> for Quantity<T, D> {
+    fn as_ref(&self) -> &T {
+        self.get_ref_work()
+    }
+}
+
 impl<T, D: Dimension> Display for Quantity<T, D>
 where
     T: Display,
     D: TypeDisplay,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         self.value.fmt(f)?;
         write!(f, " ")?;
         D::fmt(f)
     }
 }
 
+pub mod array;
+pub mod calibration;
+#[cfg(any(feature = "std", feature = "libm"))]
+pub mod decibel;
+pub mod fallible;
+pub mod filter;
+#[cfg(any(feature = "std", feature = "libm"))]
 pub mod float;
+pub mod guard;
+pub mod integration;
+pub mod iter;
+mod newtype;
+pub mod ordering;
+pub mod range;
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+pub mod sampling;
+pub mod slice;
+pub mod statistics;
+pub mod tolerance;
+
+#[cfg(feature = "nalgebra")]
+pub mod vector;
+
+#[cfg(feature = "num-complex")]
+pub mod complex;
+
+#[cfg(feature = "approx")]
+pub mod approx_eq;
@@ -0,0 +1,59 @@
+//! Implementations of the [`approx`](::approx) comparison traits for [`Quantity`]s.
+//!
+//! Only available with the `approx` feature. The values are compared in work unit, with the epsilon of their type,
+//! and only quantities of the same dimension can be compared.
+//!
+//! ```
+//! use approx::{assert_abs_diff_eq, assert_relative_eq};
+//! use rust_units::Unit;
+//! use rust_units::si_system::units::*;
+//!
+//! assert_relative_eq!(KILOMETER.build(1.0), METER.build(1000.0));
+//! assert_relative_eq!(MILE.build(1.0), KILOMETER.build(1.609344), max_relative = 1e-12);
+//! assert_abs_diff_eq!(SECOND.build(0.1 + 0.2), SECOND.build(0.3));
+//! ```
+//!
+//! ```compile_fail
+//! use approx::assert_relative_eq;
+//! use rust_units::Unit;
+//! use rust_units::si_system::units::*;
+//!
+//! assert_relative_eq!(METER.build(1.0), SECOND.build(1.0));
+//! ```
+
+use ::approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+use crate::{Dimension, Quantity};
+
+impl<T: AbsDiffEq, D: Dimension> AbsDiffEq for Quantity<T, D> {
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.get_ref_work().abs_diff_eq(other.get_ref_work(), epsilon)
+    }
+}
+
+impl<T: RelativeEq, D: Dimension> RelativeEq for Quantity<T, D> {
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+        self.get_ref_work()
+            .relative_eq(other.get_ref_work(), epsilon, max_relative)
+    }
+}
+
+impl<T: UlpsEq, D: Dimension> UlpsEq for Quantity<T, D> {
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.get_ref_work().ulps_eq(other.get_ref_work(), epsilon, max_ulps)
+    }
+}
@@ -0,0 +1,87 @@
+//! Implementations for [`Quantity`]s holding arrays and other indexable collections.
+
+use core::ops::{Index, IndexMut, MulAssign};
+
+use crate::{Dimension, Quantity};
+
+impl<T, D: Dimension, const N: usize> Quantity<[T; N], D> {
+    /// Multiplies every element of the array by the same scalar `factor`, keeping the dimension.
+    ///
+    /// The loop has no dependencies between elements, so the compiler can vectorize it.
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Unit};
+    /// use rust_units::si_system::units::*;
+    ///
+    /// let samples = METER.build([1.0; 1024]);
+    /// let scaled = samples.splat_then_scale(2.5);
+    ///
+    /// assert_eq!(scaled, METER.build([2.5; 1024]));
+    /// ```
+    pub fn splat_then_scale(mut self, factor: T) -> Self
+    where
+        T: MulAssign + Copy,
+    {
+        for x in self.get_mut_work() {
+            *x *= factor;
+        }
+        self
+    }
+}
+
+impl<T, D: Dimension> Quantity<T, D> {
+    /// Views a reference to a value in work unit as a reference to a quantity.
+    fn from_ref_work(value: &T) -> &Self {
+        // SAFETY: `Quantity` is `repr(transparent)` over its value, the other field being a `PhantomData`.
+        unsafe { &*(value as *const T as *const Self) }
+    }
+
+    /// Views a mutable reference to a value in work unit as a mutable reference to a quantity.
+    fn from_mut_work(value: &mut T) -> &mut Self {
+        // SAFETY: see `from_ref_work`.
+        unsafe { &mut *(value as *mut T as *mut Self) }
+    }
+}
+
+/// Indexing a quantity holding a collection gives the element as a quantity of the same dimension.
+///
+/// As [`Index::index`] returns a reference, the output is a `&Quantity<T, D>` viewing the element in place.
+/// For [`Copy`] elements, `quantity[i]` is thus directly an owned scalar quantity, and `&quantity[i]` borrows it.
+///
+/// ```
+/// use rust_units::{Quantity, Unit};
+/// use rust_units::si_system::{dimensions::Length, units::*};
+///
+/// let mut lengths = METER.build([1.0, 2.5, 4.0]);
+///
+/// let second: Quantity<f64, Length> = lengths[1];
+/// assert_eq!(second, METER.build(2.5));
+/// assert_eq!(lengths[2].get_in(&CENTIMETER), 400.0);
+///
+/// lengths[0] += CENTIMETER.build(50.0);
+/// assert_eq!(lengths, METER.build([1.5, 2.5, 4.0]));
+///
+/// // Any collection indexable by `usize` works.
+/// assert_eq!(METER.build(vec![3.0, 6.0])[1], METER.build(6.0));
+/// ```
+impl<C, D: Dimension> Index<usize> for Quantity<C, D>
+where
+    C: Index<usize>,
+    C::Output: Sized,
+{
+    type Output = Quantity<C::Output, D>;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        Quantity::from_ref_work(&self.get_ref_work()[index])
+    }
+}
+
+impl<C, D: Dimension> IndexMut<usize> for Quantity<C, D>
+where
+    C: IndexMut<usize>,
+    C::Output: Sized,
+{
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        Quantity::from_mut_work(&mut self.get_mut_work()[index])
+    }
+}
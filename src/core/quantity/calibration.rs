@@ -0,0 +1,60 @@
+//! Linear calibration of raw sensor readings.
+
+use core::marker::PhantomData;
+
+use derive_where::derive_where;
+
+use crate::{Dimension, Quantity, Unit};
+
+/// Affine mapping between the raw integer counts of a sensor (such as an ADC) and a [`Quantity`].
+///
+/// `counts = offset_counts + counts_per_unit * value`, where `value` is the quantity in the unit of the calibration.
+///
+/// ```
+/// use rust_units::{Quantity, Unit};
+/// use rust_units::calibration::Calibration;
+/// use rust_units::si_system::units::*;
+///
+/// // 0 kPa reads 410 counts, and each kPa adds 8 counts.
+/// let kilopascal = PASCAL.set_kilo_prefix();
+/// let sensor = Calibration::new(8.0, &kilopascal, 410.0);
+///
+/// assert_eq!(sensor.to_quantity(410), PASCAL.build(0.0));
+/// assert_eq!(sensor.to_quantity(1210), kilopascal.build(100.0));
+/// assert_eq!(sensor.to_quantity(10), kilopascal.build(-50.0));
+///
+/// assert_eq!(sensor.to_counts(kilopascal.build(100.0)), 1210);
+/// assert_eq!(sensor.to_counts(sensor.to_quantity(777)), 777);
+/// ```
+#[derive_where(Debug, Clone, Copy, PartialEq)]
+pub struct Calibration<D: Dimension> {
+    // Counts per work unit.
+    counts_per_work: f64,
+    offset_counts: f64,
+    dimension: PhantomData<D>,
+}
+
+impl<D: Dimension> Calibration<D> {
+    /// Creates a new calibration, from the number of counts per `unit` and the reading for a zero quantity.
+    ///
+    /// The unit must be proportional to the [`WorkUnit`](crate::WorkUnit).
+    pub fn new<U: Unit<f64, Dimension = D>>(counts_per_unit: f64, unit: &U, offset_counts: f64) -> Self {
+        Self {
+            counts_per_work: counts_per_unit / unit.build(1.0).get_work(),
+            offset_counts,
+            dimension: PhantomData,
+        }
+    }
+
+    /// Converts a raw reading into a quantity.
+    pub fn to_quantity(&self, counts: i32) -> Quantity<f64, D> {
+        Quantity::from_work((counts as f64 - self.offset_counts) / self.counts_per_work)
+    }
+
+    /// Converts a quantity into the closest raw reading.
+    ///
+    /// Quantities out of the range of [`i32`] saturate to its bounds.
+    pub fn to_counts(&self, quantity: Quantity<f64, D>) -> i32 {
+        num_traits::float::FloatCore::round(self.offset_counts + self.counts_per_work * quantity.get_work()) as i32
+    }
+}
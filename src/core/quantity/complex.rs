@@ -0,0 +1,78 @@
+//! Implementations for [`Quantity`]s holding [`num_complex`] numbers.
+//!
+//! Only available with the `num-complex` feature. The operators come from the generic implementations on [`Quantity`].
+
+use core::ops::Neg;
+
+use num_complex::Complex;
+
+use crate::{Dimension, Quantity};
+
+impl<T, D: Dimension> Quantity<Complex<T>, D> {
+    /// Creates a complex quantity from its real and imaginary parts, of the same dimension.
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Unit};
+    /// use rust_units::si_system::{dimensions::*, units::*};
+    /// use num_complex::Complex;
+    ///
+    /// // A 50 Ω resistor in series with a 30 Ω reactance.
+    /// let impedance = Quantity::from_parts(OHM.build(50.0), OHM.build(30.0));
+    /// let current: Quantity<Complex<f64>, Current> = AMPERE.build(Complex::new(0.0, 2.0));
+    ///
+    /// let voltage: Quantity<Complex<f64>, Voltage> = current * impedance;
+    /// assert_eq!(voltage.re(), VOLT.build(-60.0));
+    /// assert_eq!(voltage.im(), VOLT.build(100.0));
+    ///
+    /// let resistance: Quantity<f64, Impedance> = impedance.re();
+    /// let reactance: Quantity<f64, Impedance> = impedance.im();
+    /// assert_eq!((resistance, reactance), (OHM.build(50.0), OHM.build(30.0)));
+    /// assert_eq!(impedance.conj(), Quantity::from_parts(OHM.build(50.0), OHM.build(-30.0)));
+    /// ```
+    pub fn from_parts(re: Quantity<T, D>, im: Quantity<T, D>) -> Self {
+        Quantity::from_work(Complex::new(re.get_work(), im.get_work()))
+    }
+
+    /// Returns the real part, with the same dimension.
+    pub fn re(&self) -> Quantity<T, D>
+    where
+        T: Clone,
+    {
+        Quantity::from_work(self.get_ref_work().re.clone())
+    }
+
+    /// Returns the imaginary part, with the same dimension.
+    pub fn im(&self) -> Quantity<T, D>
+    where
+        T: Clone,
+    {
+        Quantity::from_work(self.get_ref_work().im.clone())
+    }
+
+    /// Returns the complex conjugate, with the same dimension.
+    pub fn conj(&self) -> Self
+    where
+        T: Clone + Neg<Output = T>,
+    {
+        let value = self.get_ref_work();
+        Quantity::from_work(Complex::new(value.re.clone(), -value.im.clone()))
+    }
+
+    /// Returns the modulus, with the same dimension.
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Unit};
+    /// use rust_units::si_system::units::*;
+    ///
+    /// let impedance = Quantity::from_parts(OHM.build(3.0), OHM.build(-4.0));
+    ///
+    /// assert_eq!(impedance.norm(), OHM.build(5.0));
+    /// ```
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub fn norm(&self) -> Quantity<T, D>
+    where
+        T: num_traits::Float,
+    {
+        Quantity::from_work(self.get_ref_work().norm())
+    }
+}
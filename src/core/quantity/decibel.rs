@@ -0,0 +1,194 @@
+//! Logarithmic ratios of [`Quantity`]s, in decibels, and logarithmic units.
+
+use core::marker::PhantomData;
+
+use derive_where::derive_where;
+
+use crate::{markers, Dimension, Quantity};
+
+/// The kind of quantity a decibel ratio is computed on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DecibelScale {
+    /// Power-like quantities (power, energy, intensity): `10 * log10(a / b)`.
+    Power,
+    /// Amplitude-like quantities (voltage, pressure, current): `20 * log10(a / b)`.
+    ///
+    /// The power carried by an amplitude is proportional to its square, hence the factor 20.
+    Amplitude,
+}
+
+impl DecibelScale {
+    /// Returns the factor applied to the decimal logarithm of the ratio: 10 for [`Power`](DecibelScale::Power),
+    /// 20 for [`Amplitude`](DecibelScale::Amplitude).
+    fn factor<T: num_traits::Float>(self) -> T {
+        match self {
+            DecibelScale::Power => T::from(10).unwrap(),
+            DecibelScale::Amplitude => T::from(20).unwrap(),
+        }
+    }
+}
+
+impl<T: num_traits::Float, D: Dimension> Quantity<T, D> {
+    /// Returns the ratio between this quantity and `reference` in decibels, on the given [`DecibelScale`].
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Unit};
+    /// use rust_units::decibel::DecibelScale;
+    /// use rust_units::si_system::units::*;
+    ///
+    /// let doubled_power = WATT.build(2.0).decibels_relative_to(WATT.build(1.0), DecibelScale::Power);
+    /// let doubled_voltage = VOLT.build(2.0).decibels_relative_to(VOLT.build(1.0), DecibelScale::Amplitude);
+    ///
+    /// assert!((doubled_power - 3.0103).abs() < 1e-4);
+    /// assert!((doubled_voltage - 6.0206).abs() < 1e-4);
+    /// assert_eq!(WATT.build(1.0).decibels_relative_to(WATT.build(1.0), DecibelScale::Power), 0.0);
+    /// ```
+    pub fn decibels_relative_to(self, reference: Self, scale: DecibelScale) -> T {
+        scale.factor::<T>() * (self.get_work() / reference.get_work()).log10()
+    }
+}
+
+/// Trait for logarithmic units, which give a level to a dimensionless ratio.
+///
+/// Unlike a [`Unit`](crate::Unit), the level is not proportional to the [`Quantity`]:
+/// a level of zero is a ratio of 1, and adding levels multiplies the ratios.
+pub trait LogarithmicUnit<T> {
+    /// The dimension of the ratio.
+    type Dimension: Dimension + markers::DimensionLess;
+
+    /// Converts a level into a ratio.
+    fn build(&self, level: T) -> Quantity<T, Self::Dimension>;
+
+    /// Retrieves the level of a ratio.
+    fn get(&self, ratio: Quantity<T, Self::Dimension>) -> T;
+}
+
+/// The decibel, on a given [`DecibelScale`].
+///
+/// A ratio `r` is `10 * log10(r)` power decibels, or `20 * log10(r)` amplitude decibels.
+///
+/// ```
+/// use rust_units::decibel::{Decibel, LogarithmicUnit};
+/// use rust_units::si_system::{dimless, SIDimensionless};
+///
+/// let db = Decibel::<SIDimensionless>::power();
+///
+/// assert_eq!(db.build(0.0), dimless(1.0));
+/// assert_eq!(db.build(20.0), dimless(100.0));
+/// assert!((db.get(db.build(3.0)) - 3.0).abs() < 1e-12);
+/// assert!((db.build(3.0).get_work() - 2.0).abs() < 1e-2);
+///
+/// let amplitude_db = Decibel::<SIDimensionless>::amplitude();
+/// assert_eq!(amplitude_db.build(20.0), dimless(10.0));
+/// ```
+#[derive_where(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Decibel<D> {
+    scale: DecibelScale,
+    dimension: PhantomData<D>,
+}
+
+impl<D> Decibel<D> {
+    /// Creates a new [`Decibel`] on the given scale.
+    pub const fn new(scale: DecibelScale) -> Self {
+        Self {
+            scale,
+            dimension: PhantomData,
+        }
+    }
+
+    /// The decibel on the [`Power`](DecibelScale::Power) scale.
+    pub const fn power() -> Self {
+        Self::new(DecibelScale::Power)
+    }
+
+    /// The decibel on the [`Amplitude`](DecibelScale::Amplitude) scale.
+    pub const fn amplitude() -> Self {
+        Self::new(DecibelScale::Amplitude)
+    }
+
+    /// Returns the scale of the decibel.
+    pub const fn scale(&self) -> DecibelScale {
+        self.scale
+    }
+}
+
+impl<T: num_traits::Float, D: Dimension + markers::DimensionLess> LogarithmicUnit<T> for Decibel<D> {
+    type Dimension = D;
+
+    fn build(&self, level: T) -> Quantity<T, D> {
+        Quantity::from_work(T::from(10).unwrap().powf(level / self.scale.factor::<T>()))
+    }
+
+    fn get(&self, ratio: Quantity<T, D>) -> T {
+        self.scale.factor::<T>() * ratio.get_work().log10()
+    }
+}
+
+/// The neper, on a given [`DecibelScale`].
+///
+/// An amplitude ratio `r` is `ln(r)` nepers, and a power ratio `r` is `ln(r) / 2` nepers,
+/// so that 1 Np is 20 / ln(10) ≈ 8.686 dB on both scales.
+///
+/// ```
+/// use rust_units::decibel::{Decibel, LogarithmicUnit, Neper};
+/// use rust_units::si_system::{dimless, SIDimensionless};
+///
+/// let np = Neper::<SIDimensionless>::amplitude();
+///
+/// assert_eq!(np.build(0.0), dimless(1.0));
+/// assert!((np.get(dimless(std::f64::consts::E)) - 1.0).abs() < 1e-12);
+///
+/// let db = Decibel::<SIDimensionless>::power();
+/// let power_np = Neper::<SIDimensionless>::power();
+/// assert!((db.get(power_np.build(1.0)) - 8.686).abs() < 1e-3);
+/// ```
+#[derive_where(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Neper<D> {
+    scale: DecibelScale,
+    dimension: PhantomData<D>,
+}
+
+impl<D> Neper<D> {
+    /// Creates a new [`Neper`] on the given scale.
+    pub const fn new(scale: DecibelScale) -> Self {
+        Self {
+            scale,
+            dimension: PhantomData,
+        }
+    }
+
+    /// The neper on the [`Power`](DecibelScale::Power) scale.
+    pub const fn power() -> Self {
+        Self::new(DecibelScale::Power)
+    }
+
+    /// The neper on the [`Amplitude`](DecibelScale::Amplitude) scale.
+    pub const fn amplitude() -> Self {
+        Self::new(DecibelScale::Amplitude)
+    }
+
+    /// Returns the scale of the neper.
+    pub const fn scale(&self) -> DecibelScale {
+        self.scale
+    }
+
+    /// Returns the factor applied to the natural logarithm of the ratio.
+    fn factor<T: num_traits::Float>(&self) -> T {
+        match self.scale {
+            DecibelScale::Power => T::from(0.5).unwrap(),
+            DecibelScale::Amplitude => T::one(),
+        }
+    }
+}
+
+impl<T: num_traits::Float, D: Dimension + markers::DimensionLess> LogarithmicUnit<T> for Neper<D> {
+    type Dimension = D;
+
+    fn build(&self, level: T) -> Quantity<T, D> {
+        Quantity::from_work((level / self.factor::<T>()).exp())
+    }
+
+    fn get(&self, ratio: Quantity<T, D>) -> T {
+        self.factor::<T>() * ratio.get_work().ln()
+    }
+}
@@ -0,0 +1,105 @@
+//! Implementations for [`Quantity`]s holding an [`Option`] or a [`Result`].
+//!
+//! These methods work on the inner option or result while keeping the dimension.
+//! To use the `?` operator, first [`transpose`](Quantity::transpose) the quantity:
+//!
+//! ```
+//! use rust_units::{Quantity, Dimension};
+//! use rust_units::si_system::dimensions::Length;
+//!
+//! fn parse_length(s: &str) -> Result<Quantity<f64, Length>, String> {
+//!     let length = Length::from_work(s.parse::<f64>()).map_err(|e| e.to_string()).transpose()?;
+//!     Ok(length)
+//! }
+//!
+//! assert_eq!(parse_length("2.5"), Ok(Length::from_work(2.5)));
+//! assert!(parse_length("two").is_err());
+//! ```
+
+use crate::{Dimension, Quantity};
+
+impl<T, D: Dimension> Quantity<Option<T>, D> {
+    /// Converts a `Quantity<Option<T>, D>` into an `Option<Quantity<T, D>>`.
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Dimension};
+    /// use rust_units::si_system::dimensions::*;
+    ///
+    /// assert_eq!(Time::from_work(Some(2.0)).transpose(), Some(Time::from_work(2.0)));
+    /// assert_eq!(Time::from_work(None::<f64>).transpose(), None);
+    /// ```
+    pub fn transpose(self) -> Option<Quantity<T, D>> {
+        self.get_work().map(Quantity::from_work)
+    }
+
+    /// Transforms the inner [`Option`] into a [`Result`], mapping `None` to `Err(err)`.
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Dimension};
+    /// use rust_units::si_system::dimensions::*;
+    ///
+    /// let missing = Time::from_work(None::<f64>).ok_or("no timestamp");
+    ///
+    /// assert_eq!(missing.transpose(), Err("no timestamp"));
+    /// assert_eq!(Time::from_work(Some(2.0)).ok_or("no timestamp").transpose(), Ok(Time::from_work(2.0)));
+    /// ```
+    pub fn ok_or<E>(self, err: E) -> Quantity<Result<T, E>, D> {
+        Quantity::from_work(self.get_work().ok_or(err))
+    }
+
+    /// Returns the contained value, or `default` if it is `None`.
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Dimension};
+    /// use rust_units::si_system::dimensions::*;
+    ///
+    /// assert_eq!(Time::from_work(None).unwrap_or(Time::from_work(1.0)), Time::from_work(1.0));
+    /// assert_eq!(Time::from_work(Some(2.0)).unwrap_or(Time::from_work(1.0)), Time::from_work(2.0));
+    /// ```
+    pub fn unwrap_or(self, default: Quantity<T, D>) -> Quantity<T, D> {
+        Quantity::from_work(self.get_work().unwrap_or(default.get_work()))
+    }
+}
+
+impl<T, E, D: Dimension> Quantity<Result<T, E>, D> {
+    /// Converts a `Quantity<Result<T, E>, D>` into a `Result<Quantity<T, D>, E>`.
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Dimension};
+    /// use rust_units::si_system::dimensions::*;
+    ///
+    /// assert_eq!(Time::from_work(Ok::<f64, ()>(2.0)).transpose(), Ok(Time::from_work(2.0)));
+    /// assert_eq!(Time::from_work(Err::<f64, ()>(())).transpose(), Err(()));
+    /// ```
+    pub fn transpose(self) -> Result<Quantity<T, D>, E> {
+        self.get_work().map(Quantity::from_work)
+    }
+
+    /// Returns the contained `Ok` value, or `default` if it is an `Err`.
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Dimension};
+    /// use rust_units::si_system::dimensions::*;
+    ///
+    /// let parsed = Length::from_work("x".parse::<f64>());
+    ///
+    /// assert_eq!(parsed.unwrap_or(Length::from_work(0.0)), Length::from_work(0.0));
+    /// ```
+    pub fn unwrap_or(self, default: Quantity<T, D>) -> Quantity<T, D> {
+        Quantity::from_work(self.get_work().unwrap_or(default.get_work()))
+    }
+
+    /// Maps the error of the inner [`Result`] with `op`, leaving an `Ok` value untouched.
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Dimension};
+    /// use rust_units::si_system::dimensions::*;
+    ///
+    /// let parsed = Length::from_work("x".parse::<f64>()).map_err(|_| "invalid length");
+    ///
+    /// assert_eq!(parsed.transpose(), Err("invalid length"));
+    /// ```
+    pub fn map_err<F, O: FnOnce(E) -> F>(self, op: O) -> Quantity<Result<T, F>, D> {
+        Quantity::from_work(self.get_work().map_err(op))
+    }
+}
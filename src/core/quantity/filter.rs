@@ -0,0 +1,121 @@
+//! Smoothing filters over series of [`Quantity`]s.
+
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+use alloc::vec::Vec;
+
+use derive_where::derive_where;
+
+use crate::{Dimension, Quantity};
+
+/// Returns the trailing moving average of `samples` over `window` samples.
+///
+/// The result has the same length as `samples`: each output is the mean of the sample at the same index
+/// and of the `window - 1` samples before it. At the start of the series, where fewer samples are available,
+/// the mean is taken over the available samples only.
+///
+/// **Panics** if `window` is zero.
+///
+/// ```
+/// use rust_units::{Quantity, Unit};
+/// use rust_units::filter::moving_average;
+/// use rust_units::si_system::units::*;
+///
+/// let noisy = [10.5, 9.5, 10.4, 9.6, 10.3, 9.7].map(|v| VOLT.build(v));
+///
+/// let smoothed = moving_average(&noisy, 2);
+///
+/// assert_eq!(smoothed.len(), noisy.len());
+/// assert_eq!(smoothed[0], VOLT.build(10.5));
+/// for v in &smoothed[1..] {
+///     assert!((v.get_in(&VOLT) - 10.0).abs() < 1e-9);
+/// }
+///
+/// // A spike only affects the windows containing it.
+/// let spiky = [1e20, 1.0, 1.0, f64::INFINITY, 1.0, 1.0].map(|v| VOLT.build(v));
+/// let smoothed = moving_average(&spiky, 2);
+///
+/// assert_eq!(smoothed[2], VOLT.build(1.0));
+/// assert!(smoothed[4].get_work().is_infinite());
+/// assert_eq!(smoothed[5], VOLT.build(1.0));
+/// ```
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+pub fn moving_average<T, D: Dimension>(samples: &[Quantity<T, D>], window: usize) -> Vec<Quantity<T, D>>
+where
+    T: num_traits::Float,
+{
+    assert!(window > 0, "the window of a moving average can not be empty");
+
+    (0..samples.len())
+        .map(|i| {
+            let count = (i + 1).min(window);
+            // Summed again for each window, so that a spike (or an infinity) does not leave a
+            // rounding error (or a NaN) in a running sum once it has left the window.
+            let sum = samples[i + 1 - count..=i]
+                .iter()
+                .fold(T::zero(), |sum, sample| sum + *sample.get_ref_work());
+            Quantity::from_work(sum / T::from(count).unwrap())
+        })
+        .collect()
+}
+
+/// First order low-pass filter (exponential smoothing), for real-time series.
+///
+/// Each [`update`](ExpFilter::update) moves the output toward the new sample by a fraction `1 - exp(-dt / tau)`,
+/// where `dt` is the time elapsed since the previous sample and `tau` the time constant of the filter.
+/// Unlike a fixed smoothing factor, this handles irregularly spaced samples.
+///
+/// `dt` and `tau` must have the same dimension, usually a [`Time`](crate::si_system::dimensions::Time).
+///
+/// ```
+/// use rust_units::{Quantity, Unit};
+/// use rust_units::filter::ExpFilter;
+/// use rust_units::si_system::units::*;
+///
+/// let tau = SECOND.build(1.0);
+/// let dt = SECOND.build(0.01);
+///
+/// let mut filter = ExpFilter::new(KELVIN.build(0.0));
+///
+/// // Step input: after one time constant, the output reached 1 - 1/e of the step.
+/// for _ in 0..100 {
+///     filter.update(KELVIN.build(1.0), dt, tau);
+/// }
+/// assert!((filter.value().get_in(&KELVIN) - (1.0 - (-1.0f64).exp())).abs() < 1e-9);
+///
+/// // It then converges toward the new value.
+/// for _ in 0..1000 {
+///     filter.update(KELVIN.build(1.0), dt, tau);
+/// }
+/// assert!((filter.value().get_in(&KELVIN) - 1.0).abs() < 1e-4);
+/// ```
+#[derive_where(Debug, Clone, Copy, PartialEq; T)]
+pub struct ExpFilter<T, D: Dimension> {
+    value: Quantity<T, D>,
+}
+
+impl<T, D: Dimension> ExpFilter<T, D> {
+    /// Creates a new filter whose output starts at `initial`.
+    pub const fn new(initial: Quantity<T, D>) -> Self {
+        Self { value: initial }
+    }
+
+    /// Returns the current output of the filter.
+    pub fn value(&self) -> Quantity<T, D>
+    where
+        T: Copy,
+    {
+        self.value
+    }
+
+    /// Feeds a new sample, taken `dt` after the previous one, and returns the new output.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub fn update<Dt: Dimension>(&mut self, sample: Quantity<T, D>, dt: Quantity<T, Dt>, tau: Quantity<T, Dt>) -> Quantity<T, D>
+    where
+        T: num_traits::Float,
+    {
+        let alpha = T::one() - (-dt.get_work() / tau.get_work()).exp();
+        let value = self.value.get_work();
+        self.value = Quantity::from_work(value + alpha * (sample.get_work() - value));
+        self.value
+    }
+}
@@ -1,9 +1,21 @@
 //! Implementation of the functions found in the [`num_traits::float`] module for [`Quantity<SIDimension, _>`].
 //!
 //! Some traits found in the [`num_traits::float`] module have been split to take into account dimension soundness.
+//!
+//! All the math functions are forwarded to [`num_traits::Float`], which calls the standard library with the `std` feature,
+//! or the [`libm`](https://docs.rs/libm) crate with the `libm` feature. The result is the same with both backends:
+//!
+//! ```
+//! use rust_units::{Dimension, si_system::dimensions::{Area, Length}};
+//! use rust_units::float::FloatSqrt;
+//!
+//! let side = Area::from_work(16.0_f64).sqrt();
+//!
+//! assert_eq!(side, Length::from_work(4.0));
+//! ```
 
 use num_traits::Pow;
-use std::num::FpCategory;
+use core::num::FpCategory;
 
 use crate::Quantity;
 
@@ -20,6 +32,7 @@ pub trait Float {
     /// let nan: Quantity<f32, Length> = Float::nan();
     ///
     /// assert!(nan.is_nan());
+    /// assert!(Quantity::<f64, Length>::nan().is_nan());
     /// ```
     fn nan() -> Self;
 
@@ -34,6 +47,7 @@ pub trait Float {
     /// assert!(infinity.is_infinite());
     /// assert!(!infinity.is_finite());
     /// assert!(infinity > Length::from_work(f32::MAX));
+    /// assert_eq!(Quantity::<f64, Length>::infinity(), Length::from_work(f64::INFINITY));
     /// ```
     fn infinity() -> Self;
 
@@ -48,6 +62,7 @@ pub trait Float {
     /// assert!(neg_infinity.is_infinite());
     /// assert!(!neg_infinity.is_finite());
     /// assert!(neg_infinity < Length::from_work(f32::MIN));
+    /// assert_eq!(Quantity::<f64, Length>::neg_infinity(), Length::from_work(f64::NEG_INFINITY));
     /// ```
     fn neg_infinity() -> Self;
 
@@ -375,7 +390,7 @@ pub trait Float {
     /// let x = Length::from_work(1.0);
     /// let y = Length::from_work(2.0);
     ///
-    /// assert_eq!(x.max(y), y);
+    /// assert_eq!(Float::max(x, y), y);
     /// ```
     fn max(self, other: Self) -> Self;
 
@@ -388,7 +403,7 @@ pub trait Float {
     /// let x = Length::from_work(1.0);
     /// let y = Length::from_work(2.0);
     ///
-    /// assert_eq!(x.min(y), x);
+    /// assert_eq!(Float::min(x, y), x);
     /// ```
     fn min(self, other: Self) -> Self;
 
@@ -404,7 +419,7 @@ pub trait Float {
     /// let y = Length::from_work(2.0);
     /// let z = Length::from_work(3.0);
     ///
-    /// assert_eq!(x.clamp(y, z), y);
+    /// assert_eq!(Float::clamp(x, y, z), y);
     /// ```
     fn clamp(self, min: Self, max: Self) -> Self;
 
@@ -1,6 +1,6 @@
 //! Implementation of the traits defined in [`super`].
 
-use std::ops::Mul;
+use core::ops::Mul;
 
 use extended_typenum::{rational, P1, U2, U3};
 use num_traits::{FloatConst, Inv, MulAdd};
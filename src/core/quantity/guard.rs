@@ -0,0 +1,117 @@
+//! Plausibility checks of [`Quantity`]s built from untrusted input.
+
+use core::fmt::{Debug, Display};
+
+use derive_where::derive_where;
+
+use crate::{Dimension, Quantity};
+
+/// Accepted range of a [`Quantity`], used to reject implausible values (for example read from the network).
+///
+/// It is built by setting a minimum and/or a maximum, both included. Without bounds, all the values are accepted,
+/// except the ones that can not be compared (such as `NaN`) if a bound is set.
+///
+/// ```
+/// use rust_units::{Quantity, Unit};
+/// use rust_units::guard::RangeGuard;
+/// use rust_units::si_system::units::*;
+///
+/// let guard = RangeGuard::new().min(KELVIN.build(0.0)).max(KELVIN.build(1e6));
+///
+/// assert_eq!(guard.validate(KELVIN.build(293.15)), Ok(KELVIN.build(293.15)));
+///
+/// let error = guard.validate(KELVIN.build(-5.0)).unwrap_err();
+/// assert_eq!(error.value(), KELVIN.build(-5.0));
+/// assert_eq!(error.to_string(), "value -5.0 is out of the range [0.0, 1000000.0] (in work unit)");
+///
+/// assert!(guard.validate(KELVIN.build(f64::NAN)).is_err());
+/// ```
+#[derive_where(Debug, Default, Clone, Copy, PartialEq; T)]
+pub struct RangeGuard<T, D: Dimension> {
+    min: Option<Quantity<T, D>>,
+    max: Option<Quantity<T, D>>,
+}
+
+impl<T, D: Dimension> RangeGuard<T, D> {
+    /// Creates a guard accepting all the values.
+    pub const fn new() -> Self {
+        Self { min: None, max: None }
+    }
+
+    /// Sets the smallest accepted value.
+    pub fn min(self, min: Quantity<T, D>) -> Self {
+        Self { min: Some(min), ..self }
+    }
+
+    /// Sets the largest accepted value.
+    pub fn max(self, max: Quantity<T, D>) -> Self {
+        Self { max: Some(max), ..self }
+    }
+
+    /// Returns `true` if the value is in the accepted range.
+    pub fn contains(&self, value: &Quantity<T, D>) -> bool
+    where
+        T: PartialOrd,
+    {
+        let above_min = match &self.min {
+            Some(min) => value >= min,
+            None => true,
+        };
+        let below_max = match &self.max {
+            Some(max) => value <= max,
+            None => true,
+        };
+        above_min && below_max
+    }
+
+    /// Returns the value if it is in the accepted range, or an [`OutOfRange`] error.
+    pub fn validate(&self, value: Quantity<T, D>) -> Result<Quantity<T, D>, OutOfRange<T, D>>
+    where
+        T: PartialOrd + Clone,
+    {
+        if self.contains(&value) {
+            Ok(value)
+        } else {
+            Err(OutOfRange {
+                value,
+                guard: self.clone(),
+            })
+        }
+    }
+}
+
+/// Error returned by [`RangeGuard::validate`].
+#[derive_where(Debug, Clone, Copy, PartialEq; T)]
+pub struct OutOfRange<T, D: Dimension> {
+    value: Quantity<T, D>,
+    guard: RangeGuard<T, D>,
+}
+
+impl<T, D: Dimension> OutOfRange<T, D> {
+    /// Returns the rejected value.
+    pub fn value(self) -> Quantity<T, D> {
+        self.value
+    }
+
+    /// Returns the guard that rejected the value.
+    pub fn guard(&self) -> &RangeGuard<T, D> {
+        &self.guard
+    }
+}
+
+impl<T: Debug, D: Dimension> Display for OutOfRange<T, D> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let bound = |f: &mut core::fmt::Formatter<'_>, bound: &Option<Quantity<T, D>>, infinite: &str| match bound {
+            Some(q) => write!(f, "{:?}", q.get_ref_work()),
+            None => f.write_str(infinite),
+        };
+        write!(f, "value {:?} is out of the range [", self.value.get_ref_work())?;
+        bound(f, &self.guard.min, "-inf")?;
+        f.write_str(", ")?;
+        bound(f, &self.guard.max, "+inf")?;
+        f.write_str("] (in work unit)")
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Debug, D: Dimension> std::error::Error for OutOfRange<T, D> {}
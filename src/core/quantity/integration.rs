@@ -0,0 +1,76 @@
+//! Numerical integration of [`Quantity`]s over a fixed step.
+//!
+//! The dimension of the derivative times the dimension of the step must be the dimension of the state.
+//! For example, a [`Velocity`](crate::si_system::dimensions::Velocity) integrated over a
+//! [`Time`](crate::si_system::dimensions::Time) step gives a [`Length`](crate::si_system::dimensions::Length).
+
+use core::ops::{Add, Mul};
+
+use crate::{Dimension, Quantity};
+
+impl<T, D: Dimension> Quantity<T, D> {
+    /// Performs one explicit Euler step: returns `self + derivative * step`.
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Unit};
+    /// use rust_units::si_system::units::*;
+    ///
+    /// let position = METER.build(2.0);
+    /// let velocity = METER.per(SECOND).build(3.0);
+    ///
+    /// let next = position.euler_step(velocity, SECOND.build(0.5));
+    ///
+    /// assert_eq!(next, METER.build(3.5));
+    /// ```
+    pub fn euler_step<Dd, Ds>(self, derivative: Quantity<T, Dd>, step: Quantity<T, Ds>) -> Self
+    where
+        T: Mul<Output = T> + Add<Output = T>,
+        Dd: Dimension + Mul<Ds, Output = D>,
+        Ds: Dimension,
+    {
+        Self::from_work(self.get_work() + derivative.get_work() * step.get_work())
+    }
+}
+
+/// Performs one step of the classical fourth order Runge-Kutta method.
+///
+/// `f` returns the derivative of the state.
+///
+/// ```
+/// use rust_units::{Quantity, Unit};
+/// use rust_units::integration::rk4_step;
+/// use rust_units::si_system::{units::*, dimless};
+///
+/// // Exponential decay: dx/dt = -x / tau.
+/// let tau = SECOND.build(2.0);
+/// let dt = SECOND.build(0.1);
+///
+/// let mut x = METER.build(1.0);
+/// for _ in 0..10 {
+///     x = rk4_step(x, dt, |x| -x / tau);
+/// }
+///
+/// let expected = (-0.5f64).exp(); // x(1 s) = exp(-1 s / tau)
+/// assert!((x.get_in(&METER) - expected).abs() < 1e-6);
+/// ```
+#[cfg(any(feature = "std", feature = "libm"))]
+pub fn rk4_step<T, D, Dd, Ds, F>(state: Quantity<T, D>, step: Quantity<T, Ds>, f: F) -> Quantity<T, D>
+where
+    T: num_traits::Float,
+    D: Dimension,
+    Dd: Dimension + Mul<Ds, Output = D>,
+    Ds: Dimension,
+    F: Fn(Quantity<T, D>) -> Quantity<T, Dd>,
+{
+    let two = T::one() + T::one();
+    let six = two + two + two;
+    let y = state.get_work();
+    let h = step.get_work();
+
+    let k1 = f(state).get_work();
+    let k2 = f(Quantity::from_work(y + k1 * h / two)).get_work();
+    let k3 = f(Quantity::from_work(y + k2 * h / two)).get_work();
+    let k4 = f(Quantity::from_work(y + k3 * h)).get_work();
+
+    Quantity::from_work(y + (k1 + two * (k2 + k3) + k4) * h / six)
+}
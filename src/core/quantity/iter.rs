@@ -0,0 +1,188 @@
+//! Extensions for iterators over [`Quantity`]s.
+
+#[cfg(feature = "std")]
+use std::{collections::HashMap, hash::Hash, ops::AddAssign};
+
+#[cfg(feature = "std")]
+use num_traits::Zero;
+
+use crate::{Dimension, Quantity};
+
+/// Trait for types holding a [`Quantity`], such as records of a data set.
+///
+/// ```
+/// use rust_units::{Quantity, Dimension};
+/// use rust_units::iter::HasQuantity;
+/// use rust_units::si_system::dimensions::Energy;
+///
+/// struct Reading {
+///     energy: Quantity<f64, Energy>,
+/// }
+///
+/// impl HasQuantity<f64, Energy> for Reading {
+///     fn quantity(&self) -> Quantity<f64, Energy> {
+///         self.energy
+///     }
+/// }
+///
+/// let reading = Reading { energy: Energy::from_work(3.0) };
+/// assert_eq!(reading.quantity(), Energy::from_work(3.0));
+/// ```
+pub trait HasQuantity<T, D: Dimension> {
+    /// Returns the held quantity.
+    fn quantity(&self) -> Quantity<T, D>;
+}
+
+impl<T: Clone, D: Dimension> HasQuantity<T, D> for Quantity<T, D> {
+    fn quantity(&self) -> Quantity<T, D> {
+        self.clone()
+    }
+}
+
+impl<'a, T, D: Dimension, H: HasQuantity<T, D>> HasQuantity<T, D> for &'a H {
+    fn quantity(&self) -> Quantity<T, D> {
+        H::quantity(*self)
+    }
+}
+
+impl<K, T: Clone, D: Dimension> HasQuantity<T, D> for (K, Quantity<T, D>) {
+    fn quantity(&self) -> Quantity<T, D> {
+        self.1.clone()
+    }
+}
+
+/// Extension trait for iterators over items holding a [`Quantity`].
+///
+/// It is implemented for all iterators whose items implement [`HasQuantity`].
+pub trait QuantityIterator<T, D: Dimension>: Iterator + Sized
+where
+    Self::Item: HasQuantity<T, D>,
+{
+    /// Sums the quantities of the items, grouped by the key extracted from each item.
+    ///
+    /// Only available with the `std` feature.
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Unit};
+    /// use rust_units::iter::QuantityIterator;
+    /// use rust_units::si_system::units::*;
+    ///
+    /// let consumption = [
+    ///     ("heating", JOULE.build(300.0)),
+    ///     ("lighting", JOULE.build(50.0)),
+    ///     ("heating", JOULE.build(200.0)),
+    /// ];
+    ///
+    /// let totals = consumption.iter().sum_by_key(|(category, _)| *category);
+    ///
+    /// assert_eq!(totals.len(), 2);
+    /// assert_eq!(totals["heating"], JOULE.build(500.0));
+    /// assert_eq!(totals["lighting"], JOULE.build(50.0));
+    /// ```
+    #[cfg(feature = "std")]
+    fn sum_by_key<K, F>(self, key: F) -> HashMap<K, Quantity<T, D>>
+    where
+        K: Eq + Hash,
+        F: Fn(&Self::Item) -> K,
+        T: AddAssign + Zero,
+    {
+        let mut sums = HashMap::new();
+        for item in self {
+            *sums
+                .entry(key(&item))
+                .or_insert_with(|| Quantity::from_work(T::zero()))
+                .get_mut_work() += item.quantity().get_work();
+        }
+        sums
+    }
+
+    /// Returns the quantity with the largest absolute value, keeping its sign, or `None` if the iterator is empty.
+    ///
+    /// If several quantities have the same absolute value, the last one is returned. `NaN`s are ignored,
+    /// unless all the values are `NaN`.
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Unit};
+    /// use rust_units::iter::QuantityIterator;
+    /// use rust_units::si_system::units::*;
+    ///
+    /// let errors = [0.2, -1.5, 0.9, 1.1].map(|e| METER.build(e));
+    ///
+    /// assert_eq!(errors.iter().abs_max(), Some(METER.build(-1.5)));
+    /// assert_eq!(errors.iter().abs_min(), Some(METER.build(0.2)));
+    /// assert_eq!(errors[..0].iter().abs_max(), None);
+    /// ```
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn abs_max(self) -> Option<Quantity<T, D>>
+    where
+        T: num_traits::Float,
+    {
+        self.map(|item| item.quantity()).reduce(|max, q| {
+            let (x, m) = (*q.get_ref_work(), *max.get_ref_work());
+            if x.abs() >= m.abs() || m.is_nan() {
+                q
+            } else {
+                max
+            }
+        })
+    }
+
+    /// Returns the quantity with the smallest absolute value, keeping its sign, or `None` if the iterator is empty.
+    ///
+    /// If several quantities have the same absolute value, the first one is returned. `NaN`s are ignored,
+    /// unless all the values are `NaN`.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn abs_min(self) -> Option<Quantity<T, D>>
+    where
+        T: num_traits::Float,
+    {
+        self.map(|item| item.quantity()).reduce(|min, q| {
+            let (x, m) = (*q.get_ref_work(), *min.get_ref_work());
+            if x.abs() < m.abs() || m.is_nan() {
+                q
+            } else {
+                min
+            }
+        })
+    }
+
+    /// Returns the harmonic mean of the quantities (`n / Σ 1/xᵢ`), or `None` if the iterator is empty.
+    ///
+    /// The result has the same dimension as the quantities. It is the mean to use for rates,
+    /// such as the average speed of a trip made of segments of equal length.
+    /// If one of the quantities is zero, the result is zero.
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Dimension};
+    /// use rust_units::iter::QuantityIterator;
+    /// use rust_units::si_system::dimensions::Velocity;
+    ///
+    /// // Same distance driven at 60 m/s, then at 40 m/s.
+    /// let speeds = [60.0, 40.0].map(Velocity::from_work);
+    ///
+    /// let average = speeds.iter().harmonic_mean().unwrap();
+    /// assert!((average - Velocity::from_work(48.0)).get_work().abs() < 1e-12);
+    ///
+    /// assert_eq!(speeds[..0].iter().harmonic_mean(), None);
+    /// ```
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn harmonic_mean(self) -> Option<Quantity<T, D>>
+    where
+        T: num_traits::Float,
+    {
+        let (count, sum) = self.fold((0usize, T::zero()), |(count, sum), item| {
+            (count + 1, sum + item.quantity().get_work().recip())
+        });
+        if count == 0 {
+            return None;
+        }
+        Some(Quantity::from_work(T::from(count)? / sum))
+    }
+}
+
+impl<I, T, D: Dimension> QuantityIterator<T, D> for I
+where
+    I: Iterator,
+    I::Item: HasQuantity<T, D>,
+{
+}
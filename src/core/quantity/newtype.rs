@@ -0,0 +1,150 @@
+//! The [`newtype_quantity`](crate::newtype_quantity) macro.
+
+/// Macro to create a strongly-typed wrapper around a [`Quantity`](crate::Quantity) of a given dimension.
+///
+/// The generated tuple struct wraps a `Quantity<T, Dim>` (`T` is [`f64`] unless specified), with a public field.
+/// It does not implement [`Deref`](std::ops::Deref), but forwards the common methods and operations:
+///
+/// - `new(value, &unit)`, `value_in(&unit)` and `quantity()`,
+/// - [`From`] conversions from and to the wrapped quantity,
+/// - [`Add`](std::ops::Add), [`Sub`](std::ops::Sub) and their assign versions with the same type, and [`Neg`](std::ops::Neg),
+/// - [`Mul`](std::ops::Mul) and [`Div`](std::ops::Div) by a `T`, and [`Div`](std::ops::Div) by the same type, which gives a `T`.
+///
+/// It also derives [`Debug`], [`Default`], [`Clone`], [`Copy`], [`PartialEq`] and [`PartialOrd`].
+///
+/// ```
+/// use rust_units::{newtype_quantity, Quantity, Unit};
+/// use rust_units::si_system::{dimensions, units::*};
+///
+/// newtype_quantity!(
+///     /// A length, in a public API.
+///     pub Length, dimensions::Length
+/// );
+/// newtype_quantity!(pub Duration, dimensions::Time, f32);
+///
+/// let a = Length::new(1.5, &METER.set_kilo_prefix());
+/// let b = Length::new(500.0, &METER);
+///
+/// let total = a + b;
+/// assert_eq!(total.value_in(&METER), 2000.0);
+/// assert_eq!(total / b, 4.0);
+/// assert_eq!(-(total * 2.0), Length::new(-4000.0, &METER));
+///
+/// let q: Quantity<f64, dimensions::Length> = total.into();
+/// assert_eq!(Length::from(q), total);
+///
+/// assert_eq!(Duration::new(2.0, &SECOND).quantity(), SECOND.build(2.0f32));
+/// ```
+///
+/// Different newtypes can not be mixed, even if they have the same dimension:
+///
+/// ```compile_fail
+/// use rust_units::{newtype_quantity, Unit};
+/// use rust_units::si_system::{dimensions, units::*};
+///
+/// newtype_quantity!(Width, dimensions::Length);
+/// newtype_quantity!(Height, dimensions::Length);
+///
+/// let _ = Width::new(1.0, &METER) + Height::new(1.0, &METER);
+/// ```
+#[macro_export]
+macro_rules! newtype_quantity {
+    ($(#[$meta:meta])* $vis:vis $Name:ident, $Dim:ty $(,)?) => {
+        $crate::newtype_quantity!($(#[$meta])* $vis $Name, $Dim, f64);
+    };
+
+    ($(#[$meta:meta])* $vis:vis $Name:ident, $Dim:ty, $T:ty $(,)?) => {
+        $(#[$meta])*
+        #[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+        $vis struct $Name(pub $crate::Quantity<$T, $Dim>);
+
+        impl $Name {
+            /// Creates a new value from the given unit.
+            pub fn new<U: $crate::Unit<$T, Dimension = $Dim>>(value: $T, unit: &U) -> Self {
+                Self(unit.build(value))
+            }
+
+            /// Returns the value in the given unit.
+            pub fn value_in<U: $crate::Unit<$T, Dimension = $Dim>>(self, unit: &U) -> $T {
+                unit.get(self.0)
+            }
+
+            /// Returns the wrapped quantity.
+            pub fn quantity(self) -> $crate::Quantity<$T, $Dim> {
+                self.0
+            }
+        }
+
+        impl ::core::convert::From<$crate::Quantity<$T, $Dim>> for $Name {
+            fn from(value: $crate::Quantity<$T, $Dim>) -> Self {
+                Self(value)
+            }
+        }
+
+        impl ::core::convert::From<$Name> for $crate::Quantity<$T, $Dim> {
+            fn from(value: $Name) -> Self {
+                value.0
+            }
+        }
+
+        impl ::core::ops::Add for $Name {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                Self(self.0 + rhs.0)
+            }
+        }
+
+        impl ::core::ops::AddAssign for $Name {
+            fn add_assign(&mut self, rhs: Self) {
+                self.0 += rhs.0
+            }
+        }
+
+        impl ::core::ops::Sub for $Name {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                Self(self.0 - rhs.0)
+            }
+        }
+
+        impl ::core::ops::SubAssign for $Name {
+            fn sub_assign(&mut self, rhs: Self) {
+                self.0 -= rhs.0
+            }
+        }
+
+        impl ::core::ops::Neg for $Name {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self(-self.0)
+            }
+        }
+
+        impl ::core::ops::Mul<$T> for $Name {
+            type Output = Self;
+
+            fn mul(self, rhs: $T) -> Self {
+                Self($crate::Quantity::from_work(self.0.get_work() * rhs))
+            }
+        }
+
+        impl ::core::ops::Div<$T> for $Name {
+            type Output = Self;
+
+            fn div(self, rhs: $T) -> Self {
+                Self($crate::Quantity::from_work(self.0.get_work() / rhs))
+            }
+        }
+
+        impl ::core::ops::Div for $Name {
+            type Output = $T;
+
+            fn div(self, rhs: Self) -> $T {
+                self.0.get_work() / rhs.0.get_work()
+            }
+        }
+    };
+}
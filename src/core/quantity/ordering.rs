@@ -0,0 +1,50 @@
+//! Total orderings of [`Quantity`]s holding floats, for use in ordered collections.
+
+use core::cmp::Ordering;
+
+use derive_where::derive_where;
+use num_traits::float::TotalOrder;
+
+use crate::{Dimension, Quantity};
+
+/// Wrapper reversing the order of a [`Quantity`], so that a [`BinaryHeap`](std::collections::BinaryHeap) becomes a min-heap.
+///
+/// Unlike [`Reverse`](std::cmp::Reverse), it implements [`Ord`] for floats,
+/// by comparing the values with [`TotalOrder::total_cmp`].
+///
+/// ```
+/// use rust_units::{Quantity, Unit};
+/// use rust_units::ordering::ReverseQuantity;
+/// use rust_units::si_system::units::*;
+/// use std::collections::BinaryHeap;
+///
+/// let mut events = BinaryHeap::new();
+/// events.push(ReverseQuantity(SECOND.build(3.0)));
+/// events.push(ReverseQuantity(SECOND.build(0.5)));
+/// events.push(ReverseQuantity(SECOND.build(2.0)));
+///
+/// assert_eq!(events.pop(), Some(ReverseQuantity(SECOND.build(0.5))));
+/// assert_eq!(events.pop().map(|t| t.0), Some(SECOND.build(2.0)));
+/// ```
+#[derive_where(Debug, Default, Clone, Copy; T)]
+pub struct ReverseQuantity<T, D: Dimension>(pub Quantity<T, D>);
+
+impl<T: TotalOrder, D: Dimension> PartialEq for ReverseQuantity<T, D> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: TotalOrder, D: Dimension> Eq for ReverseQuantity<T, D> {}
+
+impl<T: TotalOrder, D: Dimension> PartialOrd for ReverseQuantity<T, D> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: TotalOrder, D: Dimension> Ord for ReverseQuantity<T, D> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.0.get_ref_work().total_cmp(self.0.get_ref_work())
+    }
+}
@@ -0,0 +1,114 @@
+//! Closed intervals of [`Quantity`]s, for interval arithmetic.
+
+use derive_where::derive_where;
+
+use crate::{Dimension, Quantity};
+
+/// A closed interval `[start, end]` of [`Quantity`]s of the same dimension, compared on their values in work unit.
+///
+/// The bounds are included, so two ranges sharing only a bound overlap.
+///
+/// ```
+/// use rust_units::{Quantity, Unit};
+/// use rust_units::range::QuantityRange;
+/// use rust_units::si_system::units::*;
+///
+/// let a = QuantityRange::new(METER.build(0.0), METER.build(2.0));
+/// let b = QuantityRange::new(CENTIMETER.build(150.0), METER.build(3.0));
+/// let c = QuantityRange::new(METER.build(5.0), METER.build(6.0));
+///
+/// assert!(a.overlaps(&b));
+/// assert!(!a.overlaps(&c));
+///
+/// assert_eq!(a.intersect(&b), Some(QuantityRange::new(METER.build(1.5), METER.build(2.0))));
+/// assert_eq!(a.intersect(&c), None);
+///
+/// assert_eq!(a.union(&b), Some(QuantityRange::new(METER.build(0.0), METER.build(3.0))));
+/// assert_eq!(a.union(&c), None);
+///
+/// assert!(a.contains(&METER.build(2.0)));
+/// assert!(!a.contains(&METER.build(2.5)));
+/// ```
+#[derive_where(Debug, Clone, Copy, PartialEq, Eq, Hash; T)]
+pub struct QuantityRange<T, D: Dimension> {
+    start: Quantity<T, D>,
+    end: Quantity<T, D>,
+}
+
+impl<T, D: Dimension> QuantityRange<T, D> {
+    /// Creates the range `[start, end]`.
+    ///
+    /// **Panics** if `start` is greater than `end`, or if they can not be compared (such as `NaN`).
+    pub fn new(start: Quantity<T, D>, end: Quantity<T, D>) -> Self
+    where
+        T: PartialOrd,
+    {
+        assert!(start <= end, "the start of a range can not be greater than its end");
+        Self { start, end }
+    }
+
+    /// Returns the lower bound of the range.
+    pub const fn start(&self) -> &Quantity<T, D> {
+        &self.start
+    }
+
+    /// Returns the upper bound of the range.
+    pub const fn end(&self) -> &Quantity<T, D> {
+        &self.end
+    }
+
+    /// Returns `true` if the value is in the range, bounds included.
+    pub fn contains(&self, value: &Quantity<T, D>) -> bool
+    where
+        T: PartialOrd,
+    {
+        &self.start <= value && value <= &self.end
+    }
+
+    /// Returns `true` if the two ranges have at least one value in common.
+    pub fn overlaps(&self, other: &Self) -> bool
+    where
+        T: PartialOrd,
+    {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    /// Returns the values common to both ranges, or `None` if they do not [`overlap`](QuantityRange::overlaps).
+    pub fn intersect(&self, other: &Self) -> Option<Self>
+    where
+        T: PartialOrd + Clone,
+    {
+        self.overlaps(other).then(|| Self {
+            start: larger(&self.start, &other.start).clone(),
+            end: smaller(&self.end, &other.end).clone(),
+        })
+    }
+
+    /// Returns the range covering both ranges, or `None` if they do not [`overlap`](QuantityRange::overlaps),
+    /// as the union would then not be a range.
+    pub fn union(&self, other: &Self) -> Option<Self>
+    where
+        T: PartialOrd + Clone,
+    {
+        self.overlaps(other).then(|| Self {
+            start: smaller(&self.start, &other.start).clone(),
+            end: larger(&self.end, &other.end).clone(),
+        })
+    }
+}
+
+fn smaller<'a, T: PartialOrd, D: Dimension>(a: &'a Quantity<T, D>, b: &'a Quantity<T, D>) -> &'a Quantity<T, D> {
+    if b < a {
+        b
+    } else {
+        a
+    }
+}
+
+fn larger<'a, T: PartialOrd, D: Dimension>(a: &'a Quantity<T, D>, b: &'a Quantity<T, D>) -> &'a Quantity<T, D> {
+    if b > a {
+        b
+    } else {
+        a
+    }
+}
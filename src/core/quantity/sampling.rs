@@ -0,0 +1,94 @@
+//! Functions to sample ranges of [`Quantity`]s.
+
+use alloc::{vec, vec::Vec};
+
+use crate::{Dimension, Quantity};
+
+/// Returns `n` evenly spaced quantities between `start` and `end`, both included.
+///
+/// - If `n == 0`, the result is empty.
+/// - If `n == 1`, the result only contains `start`.
+///
+/// ```
+/// use rust_units::{Dimension, si_system::dimensions::Length};
+/// use rust_units::sampling::linspace;
+///
+/// let points = linspace(Length::from_work(0.0), Length::from_work(1.0), 5);
+///
+/// assert_eq!(points, [0.0, 0.25, 0.5, 0.75, 1.0].map(Length::from_work));
+/// assert!(linspace(Length::from_work(0.0), Length::from_work(1.0), 0).is_empty());
+/// assert_eq!(linspace(Length::from_work(2.0), Length::from_work(1.0), 1), [Length::from_work(2.0)]);
+/// ```
+pub fn linspace<T, D: Dimension>(start: Quantity<T, D>, end: Quantity<T, D>, n: usize) -> Vec<Quantity<T, D>>
+where
+    T: num_traits::Float,
+{
+    let start = start.get_work();
+    let end = end.get_work();
+
+    match n {
+        0 => Vec::new(),
+        1 => vec![Quantity::from_work(start)],
+        _ => {
+            let last = n - 1;
+            let step = (end - start) / T::from(last).unwrap();
+            (0..n)
+                .map(|i| {
+                    if i == last {
+                        // Avoids rounding errors on the last point.
+                        Quantity::from_work(end)
+                    } else {
+                        Quantity::from_work(start + step * T::from(i).unwrap())
+                    }
+                })
+                .collect()
+        }
+    }
+}
+
+/// Returns `n` logarithmically spaced quantities between `start` and `end`, both included.
+///
+/// The ratio between two consecutive quantities is constant, which is useful for sweeps over several decades.
+///
+/// - If `n == 0`, the result is empty.
+/// - If `n == 1`, the result only contains `start`.
+///
+/// **Panics** if `start` or `end` is not strictly positive, as the logarithm is then not defined.
+///
+/// ```
+/// use rust_units::{Dimension, si_system::dimensions::Frequency};
+/// use rust_units::sampling::logspace;
+///
+/// let sweep = logspace(Frequency::from_work(10.0), Frequency::from_work(10_000.0), 4);
+/// let expected = [10.0, 100.0, 1000.0, 10_000.0];
+///
+/// assert_eq!(sweep.len(), 4);
+/// for (f, e) in sweep.into_iter().zip(expected) {
+///     assert!((f.get_work() - e).abs() < e * 1e-12);
+/// }
+/// ```
+///
+/// ```should_panic
+/// use rust_units::{Dimension, si_system::dimensions::Frequency};
+/// use rust_units::sampling::logspace;
+///
+/// logspace(Frequency::from_work(0.0), Frequency::from_work(10.0), 3);
+/// ```
+pub fn logspace<T, D: Dimension>(start: Quantity<T, D>, end: Quantity<T, D>, n: usize) -> Vec<Quantity<T, D>>
+where
+    T: num_traits::Float,
+{
+    assert!(
+        start.get_ref_work() > &T::zero() && end.get_ref_work() > &T::zero(),
+        "logspace bounds must be strictly positive"
+    );
+
+    linspace(
+        Quantity::<T, D>::from_work(start.get_work().ln()),
+        Quantity::from_work(end.get_work().ln()),
+        n,
+    )
+    .into_iter()
+    .map(|q| Quantity::from_work(q.get_work().exp()))
+    .collect()
+}
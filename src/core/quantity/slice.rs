@@ -0,0 +1,122 @@
+//! Algorithms on slices of [`Quantity`]s.
+
+use core::ops::{Add, Mul};
+
+#[cfg(feature = "alloc")]
+use alloc::{format, string::String, vec::Vec};
+
+use num_traits::{float::TotalOrder, Zero};
+
+use crate::{Dimension, Quantity};
+#[cfg(feature = "alloc")]
+use crate::{Unit, UnitSymbol};
+
+/// Binary searches a sorted slice of quantities for the given `target`.
+///
+/// The values are compared with [`TotalOrder::total_cmp`], so `NaN`s do not break the search
+/// (positive `NaN`s are sorted after infinity).
+///
+/// Same return value as [`slice::binary_search`]: `Ok` with the index of a matching element,
+/// or `Err` with the index where `target` could be inserted to keep the slice sorted.
+///
+/// ```
+/// use rust_units::{Quantity, Unit};
+/// use rust_units::slice::binary_search;
+/// use rust_units::si_system::units::*;
+///
+/// let table = [1.0, 2.0, 5.0, 10.0].map(|x| METER.build(x));
+///
+/// assert_eq!(binary_search(&table, &METER.build(5.0)), Ok(2));
+/// assert_eq!(binary_search(&table, &METER.build(3.0)), Err(2));
+/// assert_eq!(binary_search(&table, &METER.build(f64::NAN)), Err(4));
+/// ```
+pub fn binary_search<T: TotalOrder, D: Dimension>(
+    slice: &[Quantity<T, D>],
+    target: &Quantity<T, D>,
+) -> Result<usize, usize> {
+    slice.binary_search_by(|q| q.get_ref_work().total_cmp(target.get_ref_work()))
+}
+
+/// Dimensioned dot product of two slices: the sum of the products of their elements.
+///
+/// The result has the dimension of the product of the two dimensions.
+///
+/// **Panics** if the slices do not have the same length.
+///
+/// ```
+/// use rust_units::{Quantity, Unit};
+/// use rust_units::slice::dot;
+/// use rust_units::si_system::units::*;
+///
+/// let forces = [1.0, 2.0, 3.0].map(|x| NEWTON.build(x));
+/// let displacements = [4.0, 5.0, 6.0].map(|x| METER.build(x));
+///
+/// assert_eq!(dot(&forces, &displacements), JOULE.build(32.0));
+/// ```
+pub fn dot<T, Da, Db>(a: &[Quantity<T, Da>], b: &[Quantity<T, Db>]) -> Quantity<T, Da::Output>
+where
+    T: Mul<Output = T> + Add<Output = T> + Zero + Copy,
+    Da: Dimension + Mul<Db>,
+    Db: Dimension,
+    Da::Output: Dimension,
+{
+    assert_eq!(a.len(), b.len(), "dot product of slices of different lengths");
+    Quantity::from_work(
+        a.iter()
+            .zip(b)
+            .fold(T::zero(), |acc, (a, b)| acc + *a.get_ref_work() * *b.get_ref_work()),
+    )
+}
+
+/// Returns the values in work unit of the quantities, narrowed to [`f32`]s.
+///
+/// See [`Quantity::to_f32_work`] for the precision loss.
+///
+/// ```
+/// use rust_units::{Quantity, Unit};
+/// use rust_units::slice::to_f32_work_vec;
+/// use rust_units::si_system::units::*;
+///
+/// let positions = [0.25, 1.0, 1e40].map(|x| METER.build(x));
+///
+/// assert_eq!(to_f32_work_vec(&positions), vec![0.25, 1.0, f32::INFINITY]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn to_f32_work_vec<D: Dimension>(slice: &[Quantity<f64, D>]) -> Vec<f32> {
+    slice.iter().map(|q| q.to_f32_work()).collect()
+}
+
+/// Formats the quantities in the given unit for a table column.
+///
+/// All the values have `decimals` decimal places and are right-aligned to the same width,
+/// followed by the symbol of the unit.
+///
+/// ```
+/// use rust_units::{Quantity, Unit};
+/// use rust_units::slice::format_column;
+/// use rust_units::si_system::units::*;
+///
+/// let kilojoule = JOULE.set_kilo_prefix().redefine_as("kJ");
+/// let energies = [1500.0, -32.0, 120_000.0].map(|x| JOULE.build(x));
+///
+/// let column = format_column(&energies, &kilojoule, 2);
+///
+/// assert_eq!(column, ["  1.50 kJ", " -0.03 kJ", "120.00 kJ"]);
+/// assert!(column.iter().all(|cell| cell.len() == column[0].len()));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn format_column<D: Dimension, U>(slice: &[Quantity<f64, D>], unit: &U, decimals: usize) -> Vec<String>
+where
+    U: Unit<f64, Dimension = D> + UnitSymbol,
+{
+    let values: Vec<String> = slice
+        .iter()
+        .map(|q| format!("{:.decimals$}", unit.get(*q)))
+        .collect();
+    let width = values.iter().map(String::len).max().unwrap_or(0);
+
+    values
+        .iter()
+        .map(|value| format!("{value:>width$} {}", unit.symbol()))
+        .collect()
+}
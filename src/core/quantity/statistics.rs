@@ -0,0 +1,228 @@
+//! Statistics over series of [`Quantity`]s.
+
+use core::ops::Mul;
+
+use derive_where::derive_where;
+use num_traits::float::FloatCore;
+
+use crate::{Dimension, Quantity};
+
+/// Running statistics of a stream of [`Quantity`]s: count, minimum, maximum, mean and variance.
+///
+/// The mean and the variance are updated with Welford's algorithm, which is numerically stable
+/// and does not need to store the samples.
+///
+/// ```
+/// use rust_units::{Quantity, Unit};
+/// use rust_units::statistics::Stats;
+/// use rust_units::si_system::units::*;
+///
+/// let mut stats = Stats::new();
+/// assert_eq!(stats.mean(), None);
+///
+/// for v in [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0] {
+///     stats.push(METER.build(v));
+/// }
+///
+/// assert_eq!(stats.count(), 8);
+/// assert_eq!(stats.min(), Some(METER.build(2.0)));
+/// assert_eq!(stats.max(), Some(METER.build(9.0)));
+/// assert_eq!(stats.mean(), Some(METER.build(5.0)));
+/// assert_eq!(stats.variance(), Some(METER.times(METER).build(4.0)));
+/// ```
+#[cfg(any(feature = "std", feature = "libm"))]
+#[derive_where(Debug, Clone, Copy, PartialEq; T)]
+pub struct Stats<T, D: Dimension> {
+    count: usize,
+    min: Quantity<T, D>,
+    max: Quantity<T, D>,
+    mean: Quantity<T, D>,
+    // Sum of the squared differences from the mean, in work unit squared.
+    m2: T,
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<T: num_traits::Float, D: Dimension> Default for Stats<T, D> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<T: num_traits::Float, D: Dimension> Stats<T, D> {
+    /// Creates empty statistics.
+    pub fn new() -> Self {
+        Self {
+            count: 0,
+            min: Quantity::from_work(T::infinity()),
+            max: Quantity::from_work(T::neg_infinity()),
+            mean: Quantity::from_work(T::zero()),
+            m2: T::zero(),
+        }
+    }
+
+    /// Adds a sample to the statistics.
+    pub fn push(&mut self, sample: Quantity<T, D>) {
+        let x = sample.get_work();
+        self.count += 1;
+
+        self.min = Quantity::from_work(self.min.get_work().min(x));
+        self.max = Quantity::from_work(self.max.get_work().max(x));
+
+        let mean = self.mean.get_work();
+        let delta = x - mean;
+        let mean = mean + delta / T::from(self.count).unwrap();
+        self.m2 = self.m2 + delta * (x - mean);
+        self.mean = Quantity::from_work(mean);
+    }
+
+    /// Returns the number of samples.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the smallest sample, or `None` if there is none.
+    pub fn min(&self) -> Option<Quantity<T, D>> {
+        (self.count > 0).then_some(self.min)
+    }
+
+    /// Returns the largest sample, or `None` if there is none.
+    pub fn max(&self) -> Option<Quantity<T, D>> {
+        (self.count > 0).then_some(self.max)
+    }
+
+    /// Returns the mean of the samples, or `None` if there is none.
+    pub fn mean(&self) -> Option<Quantity<T, D>> {
+        (self.count > 0).then_some(self.mean)
+    }
+
+    /// Returns the population variance of the samples, or `None` if there is none.
+    ///
+    /// Its dimension is the square of the dimension of the samples.
+    pub fn variance(&self) -> Option<Quantity<T, D::Output>>
+    where
+        D: Mul<D>,
+        D::Output: Dimension,
+    {
+        (self.count > 0).then(|| Quantity::from_work(self.m2 / T::from(self.count).unwrap()))
+    }
+}
+
+/// Exponentially weighted mean and standard deviation of a stream of [`Quantity`]s.
+///
+/// Unlike [`Stats`], recent samples weigh more than old ones, so the estimates follow a slowly drifting signal.
+/// Each [`update`](EwmaStats::update) moves the estimates toward the new sample by a fraction `alpha`, in `(0, 1]`.
+/// The first sample initializes the mean, with a zero variance.
+///
+/// ```
+/// use rust_units::{Quantity, Unit};
+/// use rust_units::statistics::EwmaStats;
+/// use rust_units::si_system::units::*;
+///
+/// let mut stats = EwmaStats::new();
+/// assert_eq!(stats.mean(), None);
+///
+/// // Stationary noise of ±1 V around 10 V.
+/// for i in 0..2000 {
+///     let noise = if i % 2 == 0 { -1.0 } else { 1.0 };
+///     stats.update(VOLT.build(10.0 + noise), 0.01);
+/// }
+///
+/// assert!((stats.mean().unwrap() - VOLT.build(10.0)).get_in(&VOLT).abs() < 0.01);
+/// assert!((stats.std_dev().unwrap() - VOLT.build(1.0)).get_in(&VOLT).abs() < 0.01);
+/// ```
+#[cfg(any(feature = "std", feature = "libm"))]
+#[derive_where(Debug, Clone, Copy, PartialEq; T)]
+pub struct EwmaStats<T, D: Dimension> {
+    mean: Option<Quantity<T, D>>,
+    // Exponentially weighted variance, in work unit squared.
+    variance: T,
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<T: num_traits::Float, D: Dimension> Default for EwmaStats<T, D> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<T: num_traits::Float, D: Dimension> EwmaStats<T, D> {
+    /// Creates empty statistics.
+    pub fn new() -> Self {
+        Self {
+            mean: None,
+            variance: T::zero(),
+        }
+    }
+
+    /// Adds a sample to the statistics, with the smoothing factor `alpha`.
+    pub fn update(&mut self, sample: Quantity<T, D>, alpha: T) {
+        let x = sample.get_work();
+        let Some(mean) = self.mean else {
+            self.mean = Some(sample);
+            return;
+        };
+
+        let delta = x - mean.get_work();
+        let increment = alpha * delta;
+        self.mean = Some(Quantity::from_work(mean.get_work() + increment));
+        self.variance = (T::one() - alpha) * (self.variance + delta * increment);
+    }
+
+    /// Returns the weighted mean of the samples, or `None` if there is none.
+    pub fn mean(&self) -> Option<Quantity<T, D>> {
+        self.mean
+    }
+
+    /// Returns the weighted standard deviation of the samples, or `None` if there is none.
+    pub fn std_dev(&self) -> Option<Quantity<T, D>> {
+        self.mean.map(|_| Quantity::from_work(self.variance.sqrt()))
+    }
+
+    /// Returns the weighted variance of the samples, or `None` if there is none.
+    ///
+    /// Its dimension is the square of the dimension of the samples.
+    pub fn variance(&self) -> Option<Quantity<T, D::Output>>
+    where
+        D: Mul<D>,
+        D::Output: Dimension,
+    {
+        self.mean.map(|_| Quantity::from_work(self.variance))
+    }
+}
+
+/// Returns the `p`-th percentile of the samples, with `p` between 0 and 100.
+///
+/// The samples are sorted in place (with [`f64::total_cmp`]), and the percentile is linearly interpolated
+/// between the two closest order statistics: with `n` samples, its rank is `p / 100 * (n - 1)`
+/// (the "linear" method of NumPy, or R's type 7). The 50th percentile is the median.
+///
+/// **Panics** if `samples` is empty or if `p` is not between 0 and 100.
+///
+/// ```
+/// use rust_units::{Quantity, Unit};
+/// use rust_units::statistics::percentile;
+/// use rust_units::si_system::units::*;
+///
+/// let mut latencies = [12.0, 15.0, 11.0, 40.0, 13.0].map(|ms| SECOND.build(ms / 1000.0));
+///
+/// assert_eq!(percentile(&mut latencies, 50.0), SECOND.build(0.013));
+/// assert_eq!(percentile(&mut latencies, 0.0), SECOND.build(0.011));
+/// assert_eq!(percentile(&mut latencies, 100.0), SECOND.build(0.040));
+///
+/// // Rank 3.6, between 15 ms and 40 ms.
+/// let p90 = percentile(&mut latencies, 90.0);
+/// assert!((p90 - SECOND.build(0.030)).get_work().abs() < 1e-12);
+/// ```
+pub fn percentile<D: Dimension>(samples: &mut [Quantity<f64, D>], p: f64) -> Quantity<f64, D> {
+    assert!(!samples.is_empty(), "percentile of an empty sample");
+    assert!((0.0..=100.0).contains(&p), "percentile out of [0, 100]: {p}");
+
+    samples.sort_unstable_by(|a, b| a.get_ref_work().total_cmp(b.get_ref_work()));
+
+    let rank = p / 100.0 * (samples.len() - 1) as f64;
+    let (below, above) = (FloatCore::floor(rank) as usize, FloatCore::ceil(rank) as usize);
+    let (low, high) = (samples[below].get_work(), samples[above].get_work());
+    Quantity::from_work(low + (high - low) * (rank - below as f64))
+}
@@ -0,0 +1,105 @@
+//! Toleranced dimensions, as used in mechanical drawings.
+
+use core::ops::{Add, Sub};
+
+use derive_where::derive_where;
+
+use crate::{Dimension, Quantity};
+
+/// A nominal [`Quantity`] with an upper and a lower deviation, such as `10 mm +0.2/-0.1`.
+///
+/// Both deviations are given as non negative quantities: the accepted range is
+/// `[nominal - minus, nominal + plus]`, bounds included.
+///
+/// ```
+/// use rust_units::{Quantity, Unit};
+/// use rust_units::tolerance::Tolerance;
+/// use rust_units::si_system::units::*;
+///
+/// let millimeter = METER.set_milli_prefix();
+///
+/// // 10 mm +0.2/-0.1
+/// let bore = Tolerance::new(millimeter.build(10.0), millimeter.build(0.2), millimeter.build(0.1));
+///
+/// assert!(bore.contains(millimeter.build(10.15)));
+/// assert!(bore.contains(millimeter.build(9.95)));
+/// assert!(!bore.contains(millimeter.build(9.85)));
+/// assert!(!bore.contains(millimeter.build(10.25)));
+/// ```
+#[derive_where(Debug, Clone, Copy, PartialEq; T)]
+pub struct Tolerance<T, D: Dimension> {
+    nominal: Quantity<T, D>,
+    plus: Quantity<T, D>,
+    minus: Quantity<T, D>,
+}
+
+impl<T, D: Dimension> Tolerance<T, D> {
+    /// Creates a new tolerance from the nominal quantity, the upper deviation `plus` and the lower deviation `minus`.
+    pub const fn new(nominal: Quantity<T, D>, plus: Quantity<T, D>, minus: Quantity<T, D>) -> Self {
+        Self { nominal, plus, minus }
+    }
+
+    /// Creates a new tolerance whose upper and lower deviations are both `deviation`.
+    pub fn symmetric(nominal: Quantity<T, D>, deviation: Quantity<T, D>) -> Self
+    where
+        T: Clone,
+    {
+        Self::new(nominal, deviation.clone(), deviation)
+    }
+
+    /// Returns the nominal quantity.
+    pub const fn nominal(&self) -> &Quantity<T, D> {
+        &self.nominal
+    }
+
+    /// Returns the upper bound of the accepted range, `nominal + plus`.
+    pub fn upper(&self) -> Quantity<T, D>
+    where
+        T: Add<Output = T> + Clone,
+    {
+        Quantity::from_work(self.nominal.get_ref_work().clone() + self.plus.get_ref_work().clone())
+    }
+
+    /// Returns the lower bound of the accepted range, `nominal - minus`.
+    pub fn lower(&self) -> Quantity<T, D>
+    where
+        T: Sub<Output = T> + Clone,
+    {
+        Quantity::from_work(self.nominal.get_ref_work().clone() - self.minus.get_ref_work().clone())
+    }
+
+    /// Returns `true` if the measurement is within the accepted range, bounds included.
+    pub fn contains(&self, measurement: Quantity<T, D>) -> bool
+    where
+        T: Add<Output = T> + Sub<Output = T> + PartialOrd + Clone,
+    {
+        self.lower() <= measurement && measurement <= self.upper()
+    }
+
+    /// Restricts the measurement to the accepted range.
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Unit};
+    /// use rust_units::tolerance::Tolerance;
+    /// use rust_units::si_system::units::*;
+    ///
+    /// let shaft = Tolerance::new(METER.build(2.0), METER.build(0.0), METER.build(0.5));
+    ///
+    /// assert_eq!(shaft.clamp(METER.build(2.5)), METER.build(2.0));
+    /// assert_eq!(shaft.clamp(METER.build(1.0)), METER.build(1.5));
+    /// assert_eq!(shaft.clamp(METER.build(1.75)), METER.build(1.75));
+    /// ```
+    pub fn clamp(&self, measurement: Quantity<T, D>) -> Quantity<T, D>
+    where
+        T: Add<Output = T> + Sub<Output = T> + PartialOrd + Clone,
+    {
+        let (lower, upper) = (self.lower(), self.upper());
+        if measurement < lower {
+            lower
+        } else if measurement > upper {
+            upper
+        } else {
+            measurement
+        }
+    }
+}
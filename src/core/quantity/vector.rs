@@ -0,0 +1,274 @@
+//! Implementations for [`Quantity`]s holding [`nalgebra`] vectors.
+//!
+//! Only available with the `nalgebra` feature.
+
+use core::ops::Mul;
+
+use nalgebra::{RealField, Rotation2, Scalar, Vector2, Vector3};
+
+use crate::{markers, Dimension, Quantity};
+
+impl<T: Scalar, D: Dimension> Quantity<Vector2<T>, D> {
+    /// Returns the `x` component of the vector, with the same dimension.
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Dimension, si_system::dimensions::Velocity};
+    /// use nalgebra::Vector2;
+    ///
+    /// let v = Velocity::from_work(Vector2::new(3.0, 4.0));
+    ///
+    /// assert_eq!(v.x(), Velocity::from_work(3.0));
+    /// ```
+    pub fn x(&self) -> Quantity<T, D> {
+        Quantity::from_work(self.get_ref_work().x.clone())
+    }
+
+    /// Returns the `y` component of the vector, with the same dimension.
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Dimension, si_system::dimensions::Velocity};
+    /// use nalgebra::Vector2;
+    ///
+    /// let v = Velocity::from_work(Vector2::new(3.0, 4.0));
+    ///
+    /// assert_eq!(v.y(), Velocity::from_work(4.0));
+    /// ```
+    pub fn y(&self) -> Quantity<T, D> {
+        Quantity::from_work(self.get_ref_work().y.clone())
+    }
+
+    /// Rotates the vector by the given angle (counterclockwise), keeping its dimension.
+    ///
+    /// The angle is taken in its work unit (radians for the SI system).
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Unit, Dimension, si_system::{dimensions::Velocity, units::DEGREE}};
+    /// use nalgebra::Vector2;
+    ///
+    /// let v = Velocity::from_work(Vector2::new(3.0, 4.0));
+    /// let rotated = v.rotated(DEGREE.build(90.0));
+    ///
+    /// assert!((rotated.x() - Velocity::from_work(-4.0)).get_work().abs() < 1e-12);
+    /// assert!((rotated.y() - Velocity::from_work(3.0)).get_work().abs() < 1e-12);
+    /// ```
+    pub fn rotated<A>(self, angle: Quantity<T, A>) -> Self
+    where
+        T: RealField,
+        A: Dimension + markers::Angle,
+    {
+        Quantity::from_work(Rotation2::new(angle.get_work()) * self.get_work())
+    }
+
+    /// Returns the polar form of the vector: its magnitude, with the same dimension, and its angle
+    /// (counterclockwise from the `x` axis, in `]-π, π]`).
+    ///
+    /// The angle is given in its work unit (radians for the SI system). See [`from_polar`](Quantity::from_polar) for the reverse operation.
+    /// For the SI system, [`to_polar_si`](Quantity::to_polar_si) returns an [`Angle`](crate::si_system::dimensions::Angle)
+    /// without having to name its dimension.
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Unit, Dimension, si_system::{dimensions::Force, units::DEGREE}};
+    /// use nalgebra::Vector2;
+    ///
+    /// let force = Force::from_work(Vector2::new(3.0, 4.0));
+    ///
+    /// let (magnitude, angle) = force.to_polar_si();
+    /// assert_eq!(magnitude, Force::from_work(5.0));
+    /// assert!((angle.get_in(&DEGREE) - 53.130102354).abs() < 1e-9);
+    ///
+    /// let back = Quantity::from_polar(magnitude, angle);
+    /// assert!((back.x() - Force::from_work(3.0)).get_work().abs() < 1e-12);
+    /// assert!((back.y() - Force::from_work(4.0)).get_work().abs() < 1e-12);
+    /// ```
+    pub fn to_polar<A>(self) -> (Quantity<T, D>, Quantity<T, A>)
+    where
+        T: RealField,
+        A: Dimension + markers::Angle,
+    {
+        let v = self.get_work();
+        let angle = v.y.clone().atan2(v.x.clone());
+        (Quantity::from_work(v.norm()), Quantity::from_work(angle))
+    }
+
+    /// Creates a vector from its polar form: its magnitude, and its angle (counterclockwise from the `x` axis).
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Unit, Dimension, si_system::{dimensions::Force, units::DEGREE}};
+    ///
+    /// let force = Quantity::from_polar(Force::from_work(2.0), DEGREE.build(90.0));
+    ///
+    /// assert!(force.x().get_work().abs() < 1e-12);
+    /// assert!((force.y() - Force::from_work(2.0)).get_work().abs() < 1e-12);
+    /// ```
+    pub fn from_polar<A>(magnitude: Quantity<T, D>, angle: Quantity<T, A>) -> Self
+    where
+        T: RealField,
+        A: Dimension + markers::Angle,
+    {
+        let (sin, cos) = angle.get_work().sin_cos();
+        let magnitude = magnitude.get_work();
+        Quantity::from_work(Vector2::new(magnitude.clone() * cos, magnitude * sin))
+    }
+}
+
+impl<T: Scalar, D: Dimension> Quantity<Vector3<T>, D> {
+    /// Returns the `x` component of the vector, with the same dimension.
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Dimension, si_system::dimensions::Velocity};
+    /// use nalgebra::Vector3;
+    ///
+    /// let v = Velocity::from_work(Vector3::new(1.0, 2.0, 3.0));
+    ///
+    /// assert_eq!(v.x(), Velocity::from_work(1.0));
+    /// ```
+    pub fn x(&self) -> Quantity<T, D> {
+        Quantity::from_work(self.get_ref_work().x.clone())
+    }
+
+    /// Returns the `y` component of the vector, with the same dimension.
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Dimension, si_system::dimensions::Velocity};
+    /// use nalgebra::Vector3;
+    ///
+    /// let v = Velocity::from_work(Vector3::new(1.0, 2.0, 3.0));
+    /// let vy: Quantity<f64, Velocity> = v.y();
+    ///
+    /// assert_eq!(vy, Velocity::from_work(2.0));
+    /// ```
+    pub fn y(&self) -> Quantity<T, D> {
+        Quantity::from_work(self.get_ref_work().y.clone())
+    }
+
+    /// Returns the `z` component of the vector, with the same dimension.
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Dimension, si_system::dimensions::Velocity};
+    /// use nalgebra::Vector3;
+    ///
+    /// let v = Velocity::from_work(Vector3::new(1.0, 2.0, 3.0));
+    ///
+    /// assert_eq!(v.z(), Velocity::from_work(3.0));
+    /// ```
+    pub fn z(&self) -> Quantity<T, D> {
+        Quantity::from_work(self.get_ref_work().z.clone())
+    }
+
+    /// Returns the Euclidean norm of the vector, with the same dimension.
+    ///
+    /// The other operations come from the generic implementations on [`Quantity`]: vectors of the same dimension
+    /// can be added and subtracted, and they can be multiplied or divided by scalar quantities of any dimension.
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Dimension, si_system::dimensions::*};
+    /// use nalgebra::Vector3;
+    ///
+    /// let thrust = Force::from_work(Vector3::new(0.0, 0.0, 10.0));
+    /// let wind = Force::from_work(Vector3::new(3.0, 4.0, -10.0));
+    ///
+    /// let total = thrust + wind;
+    /// let magnitude: Quantity<f64, Force> = total.norm();
+    /// assert_eq!(magnitude, Force::from_work(5.0));
+    ///
+    /// let acceleration: Quantity<Vector3<f64>, Acceleration> = total / Mass::from_work(2.0);
+    /// assert_eq!(acceleration.norm(), Acceleration::from_work(2.5));
+    /// ```
+    ///
+    /// Vectors of different dimensions can not be added:
+    ///
+    /// ```compile_fail
+    /// use rust_units::{Dimension, si_system::dimensions::*};
+    /// use nalgebra::Vector3;
+    ///
+    /// let force = Force::from_work(Vector3::new(1.0, 2.0, 3.0));
+    /// let velocity = Velocity::from_work(Vector3::new(1.0, 2.0, 3.0));
+    ///
+    /// let _ = force + velocity;
+    /// ```
+    pub fn norm(&self) -> Quantity<T, D>
+    where
+        T: RealField,
+    {
+        Quantity::from_work(self.get_ref_work().norm())
+    }
+
+    /// Dot product of two vectors. The result has the dimension of the product of the two dimensions.
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Dimension, si_system::dimensions::{Energy, Force, Length}};
+    /// use nalgebra::Vector3;
+    ///
+    /// let force = Force::from_work(Vector3::new(1.0, 2.0, 3.0));
+    /// let displacement = Length::from_work(Vector3::new(4.0, 5.0, 6.0));
+    ///
+    /// let work: Quantity<f64, Energy> = force.dot(displacement);
+    /// assert_eq!(work, Energy::from_work(32.0));
+    /// ```
+    pub fn dot<Db>(self, other: Quantity<Vector3<T>, Db>) -> Quantity<T, D::Output>
+    where
+        T: RealField,
+        Db: Dimension,
+        D: Mul<Db, Output: Dimension>,
+    {
+        Quantity::from_work(self.get_work().dot(&other.get_work()))
+    }
+
+    /// Returns the angle between two vectors, whatever their dimensions, in `[0, π]`.
+    ///
+    /// The angle is given in its work unit (radians for the SI system).
+    /// Returns `None` if one of the vectors is zero, as the angle is then undefined.
+    ///
+    /// For the SI system, [`angle_between`](Quantity::angle_between) returns an [`Angle`](crate::si_system::dimensions::Angle)
+    /// without having to name its dimension.
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Unit, Dimension, si_system::{dimensions::*, units::DEGREE}};
+    /// use nalgebra::Vector3;
+    ///
+    /// let force = Force::from_work(Vector3::new(0.0, 0.0, 9.81));
+    /// let velocity = Velocity::from_work(Vector3::new(3.0, 4.0, 0.0));
+    ///
+    /// let angle = force.angle_to::<_, Angle>(velocity).unwrap();
+    /// assert!((angle.get_in(&DEGREE) - 90.0).abs() < 1e-12);
+    ///
+    /// let opposite = Velocity::from_work(Vector3::new(0.0, 0.0, -1.0));
+    /// assert!((force.angle_to::<_, Angle>(opposite).unwrap().get_in(&DEGREE) - 180.0).abs() < 1e-12);
+    ///
+    /// assert_eq!(force.angle_to::<_, Angle>(Velocity::from_work(Vector3::zeros())), None);
+    /// ```
+    pub fn angle_to<Db, A>(&self, other: Quantity<Vector3<T>, Db>) -> Option<Quantity<T, A>>
+    where
+        T: RealField,
+        Db: Dimension,
+        A: Dimension + markers::Angle,
+    {
+        let (a, b) = (self.get_ref_work(), other.get_ref_work());
+        if a.norm().is_zero() || b.norm().is_zero() {
+            return None;
+        }
+        // More accurate than the arc cosine of the normalized dot product for nearly (anti)parallel vectors.
+        Some(Quantity::from_work(a.cross(b).norm().atan2(a.dot(b))))
+    }
+
+    /// Cross product of two vectors. The result has the dimension of the product of the two dimensions.
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Dimension, si_system::dimensions::{Force, Length, Torque}};
+    /// use nalgebra::Vector3;
+    ///
+    /// let lever = Length::from_work(Vector3::new(2.0, 0.0, 0.0));
+    /// let force = Force::from_work(Vector3::new(0.0, 3.0, 0.0));
+    ///
+    /// let torque: Quantity<Vector3<f64>, Torque> = lever.cross(force);
+    /// assert_eq!(torque, Torque::from_work(Vector3::new(0.0, 0.0, 6.0)));
+    /// ```
+    pub fn cross<Db>(self, other: Quantity<Vector3<T>, Db>) -> Quantity<Vector3<T>, D::Output>
+    where
+        T: RealField,
+        Db: Dimension,
+        D: Mul<Db, Output: Dimension>,
+    {
+        Quantity::from_work(self.get_work().cross(&other.get_work()))
+    }
+}
@@ -0,0 +1,62 @@
+//! Assertion helpers for the test suites of crates defining their own units.
+//!
+//! Only available with the `testing` feature.
+
+use crate::Unit;
+
+/// The relative tolerance of [`test_roundtrip`].
+pub const ROUNDTRIP_TOLERANCE: f64 = 1e-12;
+
+/// Asserts that converting `value` into a quantity with `unit` and back gives `value`,
+/// within a relative tolerance of [`ROUNDTRIP_TOLERANCE`] (absolute for values smaller than 1).
+///
+/// **Panics** with the value and the round-tripped value if the check fails.
+///
+/// ```
+/// use rust_units::testing::test_roundtrip;
+/// use rust_units::si_system::units::*;
+///
+/// for value in [0.0, 1.0, -2.5, 1e-9, 6.02e23] {
+///     test_roundtrip(&METER, value);
+///     test_roundtrip(&KILOMETER, value);
+///     test_roundtrip(&INCH, value);
+///     test_roundtrip(&MILE, value);
+///     test_roundtrip(&HOUR, value);
+///     test_roundtrip(&DEGREE, value);
+///     test_roundtrip(&KILOGRAM.set_milli_prefix(), value);
+/// }
+/// ```
+///
+/// ```should_panic
+/// use rust_units::{Dimension, Quantity, Unit};
+/// use rust_units::testing::test_roundtrip;
+/// use rust_units::si_system::dimensions::Length;
+///
+/// // A broken unit, forgetting to convert back.
+/// struct Broken;
+///
+/// impl Unit<f64> for Broken {
+///     type Dimension = Length;
+///
+///     fn build(&self, value: f64) -> Quantity<f64, Length> {
+///         Length::from_work(value * 0.3048)
+///     }
+///
+///     fn get(&self, quantity: Quantity<f64, Length>) -> f64 {
+///         quantity.get_work()
+///     }
+/// }
+///
+/// test_roundtrip(&Broken, 1.0);
+/// ```
+#[track_caller]
+pub fn test_roundtrip<U: Unit<f64>>(unit: &U, value: f64) {
+    let roundtrip = unit.get(unit.build(value));
+    let tolerance = ROUNDTRIP_TOLERANCE * value.abs().max(1.0);
+    assert!(
+        (roundtrip - value).abs() <= tolerance,
+        "the unit does not round-trip: {} became {}",
+        value,
+        roundtrip
+    );
+}
@@ -3,8 +3,8 @@
 //! Units are typed objects that implement the [`Unit`] trait.
 //! They are not types, but values, so we can operate on them at runtime, create them, store them, ...
 
-use std::marker::PhantomData;
-use std::ops::*;
+use core::marker::PhantomData;
+use core::ops::*;
 
 mod proportional_unit;
 pub use proportional_unit::*;
@@ -34,6 +34,74 @@ pub trait Unit<T> {
 
     /// Retrieves the value of a [`Quantity`].
     fn get(&self, quantity: Quantity<T, Self::Dimension>) -> T;
+
+    /// Same as [`build`](Unit::build), but returns `None` if the value in work unit is not finite
+    /// (for example if the conversion overflowed).
+    ///
+    /// ```
+    /// use rust_units::{Unit, WorkPropUnit, si_system::dimensions::Length};
+    ///
+    /// let huge = WorkPropUnit::<f64, Length>::new(1e300);
+    ///
+    /// assert_eq!(huge.build_checked(2.0), Some(huge.build(2.0)));
+    /// assert_eq!(huge.build_checked(1e10), None);
+    /// ```
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn build_checked(&self, value: T) -> Option<Quantity<T, Self::Dimension>>
+    where
+        T: num_traits::Float,
+    {
+        let quantity = self.build(value);
+        quantity.get_ref_work().is_finite().then_some(quantity)
+    }
+
+    /// Retrieves the values of all the quantities of a slice.
+    ///
+    /// With a `dyn Unit`, this makes a single dynamic call for the whole slice.
+    ///
+    /// ```
+    /// use rust_units::{Unit, si_system::{dimensions::Length, units::*}};
+    ///
+    /// let unit: Box<dyn Unit<f64, Dimension = Length>> = Box::new(METER.set_kilo_prefix());
+    /// let distances = [500.0, 1500.0, 42195.0].map(|x| METER.build(x));
+    ///
+    /// assert_eq!(unit.get_all(&distances), vec![0.5, 1.5, 42.195]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn get_all(&self, quantities: &[Quantity<T, Self::Dimension>]) -> alloc::vec::Vec<T>
+    where
+        T: Clone,
+    {
+        quantities.iter().map(|q| self.get(q.clone())).collect()
+    }
+}
+
+/// Trait for units whose symbol is known for the whole program (for example `"m"` or `"°"`).
+///
+/// Units built by combining other units generally do not implement it, as their symbol is built at runtime
+/// by their [`Display`](std::fmt::Display) implementation.
+pub trait UnitSymbol {
+    /// Returns the symbol of the unit.
+    fn symbol(&self) -> &'static str;
+}
+
+/// Converts a value from the unit `from` to the unit `to`, which must have the same dimension.
+///
+/// The value goes through the work unit, as with `to.get(from.build(value))`.
+///
+/// ```
+/// use rust_units::convert;
+/// use rust_units::si_system::units::*;
+///
+/// assert_eq!(convert(1.0, &KILOMETER, &METER), 1000.0);
+/// assert_eq!(convert(90.0, &MINUTE, &HOUR), 1.5);
+/// ```
+pub fn convert<T, U1, U2>(value: T, from: &U1, to: &U2) -> T
+where
+    U1: Unit<T>,
+    U2: Unit<T, Dimension = U1::Dimension>,
+{
+    to.get(from.build(value))
 }
 
 /// Same as [`Unit`] but for types instead of values.
@@ -1,5 +1,6 @@
 //! This module contains the [`WorkProportionalUnit`] trait and the [`WorkPropUnit`] struct.
 use derive_where::derive_where;
+use num_traits::Inv;
 
 use super::*;
 
@@ -55,6 +56,69 @@ impl<K: Clone, D: Dimension> WorkPropUnit<K, D> {
             dimension: PhantomData,
         }
     }
+
+    /// Creates a new [`WorkPropUnit`] from the number of work units in one of this unit.
+    ///
+    /// One unit is worth `work_per_unit` work units, so Work = U * `work_per_unit`.
+    /// This is the same as [`new`](WorkPropUnit::new), with an explicit name.
+    ///
+    /// ```
+    /// use rust_units::{Unit, WorkPropUnit, si_system::dimensions::Length};
+    ///
+    /// let kilometer = WorkPropUnit::<f64, Length>::from_work_per_unit(1000.0);
+    ///
+    /// assert_eq!(kilometer.build(2.0).get_work(), 2000.0);
+    /// ```
+    pub fn from_work_per_unit(work_per_unit: K) -> Self {
+        Self::new(work_per_unit)
+    }
+
+    /// Creates a new [`WorkPropUnit`] from the number of this unit in one work unit.
+    ///
+    /// One work unit is worth `unit_per_work` units, so U = Work * `unit_per_work`.
+    ///
+    /// ```
+    /// use rust_units::{Unit, WorkPropUnit, si_system::dimensions::Length};
+    ///
+    /// let kilometer = WorkPropUnit::<f64, Length>::from_unit_per_work(0.001);
+    ///
+    /// assert_eq!(kilometer, WorkPropUnit::from_work_per_unit(1000.0));
+    /// assert_eq!(kilometer.build(2.0).get_work(), 2000.0);
+    /// ```
+    pub fn from_unit_per_work(unit_per_work: K) -> Self
+    where
+        K: Inv<Output = K>,
+    {
+        Self::new(unit_per_work.inv())
+    }
+
+    /// Returns the number of work units in one of this unit, which is its proportionality constant.
+    ///
+    /// Unlike [`prop_constant`](WorkProportionalUnit::prop_constant), it does not need the type of the quantities.
+    pub fn work_per_unit(&self) -> K {
+        self.prop_constant.clone()
+    }
+}
+
+impl<D: Dimension> WorkPropUnit<f64, D> {
+    /// Creates the [`WorkPropUnit`] equivalent to any unit of the same dimension, such as a prefixed unit.
+    ///
+    /// The proportionality constant is measured as the value in work unit of one `unit`.
+    /// This is only correct if `unit` is itself proportional to the [`WorkUnit`]: a unit with an offset
+    /// (such as a temperature scale whose zero is not the absolute zero) would lose it.
+    ///
+    /// ```
+    /// use rust_units::{Unit, WorkPropUnit};
+    /// use rust_units::si_system::units::*;
+    ///
+    /// let kilometer = WorkPropUnit::from_unit(&KILOMETER);
+    ///
+    /// assert_eq!(kilometer.work_per_unit(), 1000.0);
+    /// assert_eq!(kilometer.build(2.5), KILOMETER.build(2.5));
+    /// ```
+    pub fn from_unit<U: Unit<f64, Dimension = D>>(unit: &U) -> Self {
+        Self::new(unit.build(1.0).get_work())
+    }
 }
 
 impl<K: Clone, D: Dimension, T> Unit<T> for WorkPropUnit<K, D>
@@ -1,4 +1,5 @@
 #![warn(missing_docs)]
+#![cfg_attr(not(feature = "std"), no_std)]
 
 //! Provides compile-time dimensional analysis and ease of unit manipulation.
 //! 
@@ -10,6 +11,39 @@
 //!   We call this unit the `work` unit. 
 //!   We can then set or retrieve the contained numerical value in work unit or any other unit by specifying it when doing so.
 
+//!
+//! # Features
+//! - `std` (default): the float math functions (`sqrt`, `powi`, `sin`, ...) are provided by the standard library.
+//!   Also enables `alloc`, [`QuantityIterator::sum_by_key`](iter::QuantityIterator::sum_by_key) and the runtime units of the SI system
+//!   (the `si_system::runtime` and `si_system::registry` modules).
+//!   Without it, the crate is `#![no_std]`.
+//! - `alloc`: the helpers returning collections, such as [`sampling::linspace`] or [`Unit::get_all`],
+//!   for `no_std` targets with an allocator.
+//! - `libm`: the float math functions are provided by the [`libm`](https://docs.rs/libm) crate instead.
+//!   Use it with `default-features = false` on targets without the standard library.
+//! - `nalgebra`: support for quantities holding [`nalgebra`](https://docs.rs/nalgebra) vectors.
+//! - `num-complex`: support for quantities holding [`num_complex`](https://docs.rs/num-complex) numbers, see the `complex` module.
+//! - `serde`: serialization of the quantities of the SI system, see the `si_system::serialization` module.
+//! - `chrono`: conversions between time quantities and [`chrono::TimeDelta`](https://docs.rs/chrono), see the `si_system::duration` module.
+//! - `schemars`: JSON schemas of the serialized quantities of the SI system, see the `si_system::schema` module.
+//! - `rustfft`: spectra of series of quantities, see the `si_system::spectrum` module.
+//! - `derive`: the `CheckDimensions` derive macro, see [`si_system::runtime::CheckDimensions`].
+//! - `approx`: approximate comparisons of quantities with the [`approx`](https://docs.rs/approx) crate, see the `approx_eq` module.
+//! - `testing`: assertion helpers for the test suites of crates defining their own units, see the `testing` module.
+//!
+//! The `serde`, `chrono`, `schemars`, `rustfft`, `derive` and `testing` features enable `std`.
+//!
+//! Without `std` or `libm`, the quantities, units and their arithmetic are still available,
+//! but not the items needing float math (the `float`, `decibel` and `sampling` modules,
+//! [`Quantity::sqrt`], the running statistics, ...). For example, for a microcontroller:
+//!
+//! ```text
+//! cargo build --no-default-features --target thumbv7em-none-eabihf
+//! ```
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 mod core;
 pub use core::*;
 
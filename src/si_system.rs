@@ -166,14 +166,39 @@ pub use dimensions::{SISystem, SISystemWithAngles};
 
 pub mod units;
 
+pub mod constants;
+
+pub mod control;
+
+#[cfg(feature = "std")]
+pub mod runtime;
+
+pub mod temperature;
+
+#[cfg(feature = "std")]
+pub mod registry;
+
+#[cfg(feature = "serde")]
+pub mod serialization;
+
+#[cfg(feature = "chrono")]
+pub mod duration;
+
+#[cfg(feature = "schemars")]
+pub mod schema;
+
+#[cfg(feature = "rustfft")]
+pub mod spectrum;
+
 use derive_where::derive_where;
 use extended_typenum::{operator_aliases::Sum, U0, U1};
-use std::{marker::PhantomData, ops::*};
+use core::{marker::PhantomData, ops::*};
 
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::float::{FloatAngleToDimless, FloatAtan2, FloatDimlessToAngle, FloatIntegerDecode};
 use crate::{
-    float::{FloatAngleToDimless, FloatAtan2, FloatDimlessToAngle, FloatIntegerDecode},
     markers,
-    si_system::dimensions::Angle,
+    si_system::dimensions::{Angle, AngularVelocity, Length, Speed, Time},
     Dimension, Quantity,
 };
 
@@ -334,7 +359,7 @@ macro_rules! si_add_dim {
             $crate::si_system::inners::Dimensionless
         >>;
         $(impl extended_typenum::TypeDisplay for $DimID {
-            fn fmt(f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            fn fmt(f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
                 write!(f, $str)
             }
         })?
@@ -354,6 +379,7 @@ macro_rules! si_add_dim {
     };
 }
 
+#[cfg(any(feature = "std", feature = "libm"))]
 impl<T: num_traits::Float, D> FloatAngleToDimless<Quantity<T, SIDimensionless>>
     for Quantity<T, SIDimension<D>>
 where
@@ -377,6 +403,7 @@ where
     }
 }
 
+#[cfg(any(feature = "std", feature = "libm"))]
 impl<T: num_traits::Float, D> FloatDimlessToAngle<Quantity<T, SIDimension<D>>>
     for Quantity<T, SIDimensionless>
 where
@@ -395,6 +422,7 @@ where
     }
 }
 
+#[cfg(any(feature = "std", feature = "libm"))]
 impl<T: num_traits::Float, D> FloatAtan2<Quantity<T, Angle>> for Quantity<T, SIDimension<D>>
 where
     SIDimension<D>: Dimension + Div,
@@ -405,6 +433,7 @@ where
     }
 }
 
+#[cfg(any(feature = "std", feature = "libm"))]
 impl<T: num_traits::Float, D>
     FloatIntegerDecode<
         Quantity<u64, SIDimension<D>>,
@@ -429,4 +458,176 @@ where
             Quantity::from_work(sgn),
         )
     }
-}
\ No newline at end of file
+}
+
+impl<T: num_traits::Signed, D> Quantity<T, SIDimension<D>>
+where
+    SIDimension<D>: Dimension,
+{
+    /// Returns the sign of the quantity, as a dimensionless quantity (see [`num_traits::Signed::signum`]).
+    ///
+    /// Unlike [`FloatSignum::signum`](crate::float::FloatSignum::signum), which returns the sign as a plain number,
+    /// it is also available for signed integers.
+    ///
+    /// ```
+    /// use rust_units::{Dimension, Unit};
+    /// use rust_units::si_system::{dimless, dimensions::Length, units::*};
+    ///
+    /// assert_eq!((-METER.build(3.0)).signum_dimless(), dimless(-1.0));
+    /// assert_eq!(METER.build(0.0).signum_dimless(), dimless(1.0));
+    /// assert_eq!(Length::from_work(-7i32).signum_dimless(), dimless(-1));
+    /// ```
+    pub fn signum_dimless(self) -> Quantity<T, SIDimensionless> {
+        dimless(self.get_work().signum())
+    }
+}
+
+impl<T: Mul<Output = T>> Quantity<T, AngularVelocity> {
+    /// Returns the tangential speed of a point at distance `radius` from the rotation axis (v = ω⋅r).
+    ///
+    /// The angle is taken in radians and then dropped, as the tangential speed is a plain [`Speed`].
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Unit};
+    /// use rust_units::si_system::units::*;
+    /// use std::f64::consts::PI;
+    ///
+    /// let wheel = RPM.build(60.0);
+    /// let speed = wheel.tangential_speed(METER.build(0.5));
+    ///
+    /// assert!((speed.get_in(&METER.per(SECOND)) - PI).abs() < 1e-12);
+    /// ```
+    pub fn tangential_speed(self, radius: Quantity<T, Length>) -> Quantity<T, Speed> {
+        Quantity::from_work(self.get_work() * radius.get_work())
+    }
+}
+
+impl<T: Div<Output = T>> Quantity<T, Speed> {
+    /// Returns the angular velocity of a point moving at this tangential speed at distance `radius`
+    /// from the rotation axis (ω = v/r). Inverse of [`tangential_speed`](Quantity::tangential_speed).
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Unit};
+    /// use rust_units::si_system::units::*;
+    ///
+    /// let speed = METER.per(SECOND).build(3.0);
+    /// let rotation = speed.angular_velocity_at(METER.build(1.5));
+    ///
+    /// assert_eq!(rotation, RADIAN.per(SECOND).build(2.0));
+    /// ```
+    pub fn angular_velocity_at(self, radius: Quantity<T, Length>) -> Quantity<T, AngularVelocity> {
+        Quantity::from_work(self.get_work() / radius.get_work())
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl<T: nalgebra::RealField, D> Quantity<nalgebra::Vector3<T>, SIDimension<D>>
+where
+    SIDimension<D>: Dimension,
+{
+    /// Returns the [`Angle`] between two vectors, whatever their dimensions, in `[0, π]` radians.
+    ///
+    /// Same as [`angle_to`](Quantity::angle_to), with the dimension of the angle fixed to [`Angle`].
+    /// Returns `None` if one of the vectors is zero.
+    ///
+    /// ```
+    /// use rust_units::{Dimension, Unit, si_system::{dimensions::*, units::DEGREE}};
+    /// use nalgebra::Vector3;
+    ///
+    /// let force = Force::from_work(Vector3::new(1.0, 1.0, 0.0));
+    /// let displacement = Length::from_work(Vector3::new(2.0, 0.0, 0.0));
+    ///
+    /// let angle = force.angle_between(displacement).unwrap();
+    /// assert!((angle.get_in(&DEGREE) - 45.0).abs() < 1e-12);
+    ///
+    /// assert_eq!(force.angle_between(Length::from_work(Vector3::zeros())), None);
+    /// ```
+    pub fn angle_between<Db: Dimension>(&self, other: Quantity<nalgebra::Vector3<T>, Db>) -> Option<Quantity<T, Angle>> {
+        self.angle_to(other)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl<T: nalgebra::RealField, D> Quantity<nalgebra::Vector2<T>, SIDimension<D>>
+where
+    SIDimension<D>: Dimension,
+{
+    /// Returns the polar form of the vector: its magnitude, and its [`Angle`] in `]-π, π]` radians.
+    ///
+    /// Same as [`to_polar`](Quantity::to_polar), with the dimension of the angle fixed to [`Angle`].
+    ///
+    /// ```
+    /// use rust_units::{Dimension, Quantity, Unit, si_system::{dimensions::Velocity, units::DEGREE}};
+    /// use nalgebra::Vector2;
+    ///
+    /// let wind = Velocity::from_work(Vector2::new(0.0, -2.0));
+    ///
+    /// let (speed, heading) = wind.to_polar_si();
+    /// assert_eq!(speed, Velocity::from_work(2.0));
+    /// assert!((heading.get_in(&DEGREE) + 90.0).abs() < 1e-12);
+    ///
+    /// let back = Quantity::from_polar(speed, heading);
+    /// assert!((back.y() - Velocity::from_work(-2.0)).get_work().abs() < 1e-12);
+    /// ```
+    pub fn to_polar_si(self) -> (Quantity<T, SIDimension<D>>, Quantity<T, Angle>) {
+        self.to_polar()
+    }
+}
+
+impl Quantity<f64, Time> {
+    /// Formats the duration as `HH:MM:SS.mmm`, rounded to the millisecond.
+    ///
+    /// Negative durations start with a `-`, and the hours are not wrapped after 24.
+    ///
+    /// ```
+    /// use rust_units::Unit;
+    /// use rust_units::si_system::units::*;
+    ///
+    /// assert_eq!(SECOND.build(5025.678).format_clock(), "01:23:45.678");
+    /// assert_eq!(SECOND.build(0.25).format_clock(), "00:00:00.250");
+    /// assert_eq!(SECOND.build(-61.5).format_clock(), "-00:01:01.500");
+    /// assert_eq!(SECOND.build(100_000.0).format_clock(), "27:46:40.000");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn format_clock(self) -> alloc::string::String {
+        let seconds = self.get_work();
+        let millis = num_traits::float::FloatCore::round(num_traits::float::FloatCore::abs(seconds) * 1000.0) as u64;
+        let sign = if seconds < 0.0 && millis > 0 { "-" } else { "" };
+
+        let (hours, millis) = (millis / 3_600_000, millis % 3_600_000);
+        let (minutes, millis) = (millis / 60_000, millis % 60_000);
+        let (seconds, millis) = (millis / 1000, millis % 1000);
+        alloc::format!("{sign}{hours:02}:{minutes:02}:{seconds:02}.{millis:03}")
+    }
+
+    /// Splits the elapsed time into the number of whole `period`s and the phase within the current period.
+    ///
+    /// The phase is in `[0, period)`, and `count * period + phase` is the elapsed time.
+    ///
+    /// **Panics** if the elapsed time is negative, or if `period` is not strictly positive.
+    ///
+    /// ```
+    /// use rust_units::Unit;
+    /// use rust_units::si_system::units::*;
+    ///
+    /// let period = SECOND.build(60.0);
+    /// let (count, phase) = SECOND.build(222.0).split_by_period(period);
+    ///
+    /// assert_eq!(count, 3);
+    /// assert!((phase.get_in(&SECOND) - 0.7 * 60.0).abs() < 1e-9);
+    /// ```
+    pub fn split_by_period(self, period: Quantity<f64, Time>) -> (u64, Quantity<f64, Time>) {
+        let (elapsed, period) = (self.get_work(), period.get_work());
+        assert!(elapsed >= 0.0, "the elapsed time can not be negative");
+        assert!(period > 0.0, "the period must be strictly positive");
+
+        let count = num_traits::float::FloatCore::floor(elapsed / period);
+        // Rounding errors can leave the phase slightly out of [0, period).
+        let phase = (elapsed - count * period).clamp(0.0, period);
+        if phase == period {
+            (count as u64 + 1, Quantity::from_work(0.0))
+        } else {
+            (count as u64, Quantity::from_work(phase))
+        }
+    }
+}
@@ -0,0 +1,53 @@
+//! Physical constants, as [`Quantity`]s of the SI system.
+//!
+//! The values are the exact ones of the 2019 definition of the SI (except for [`STANDARD_GRAVITY`],
+//! which is exact by convention). As they are typed quantities, the dimension of the results is checked:
+//!
+//! ```
+//! use rust_units::{Quantity, Unit};
+//! use rust_units::si_system::{constants, dimensions::*, units::*};
+//!
+//! let mass = KILOGRAM.build(2.0);
+//! let momentum: Quantity<f64, Momentum> = mass * constants::SPEED_OF_LIGHT;
+//!
+//! assert_eq!(momentum.get_work(), 599_584_916.0);
+//! ```
+//!
+//! Note that [`STANDARD_GRAVITY`] has the same name as the [`units::STANDARD_GRAVITY`](super::units::STANDARD_GRAVITY) unit,
+//! so the constants are best used with their module path when the units are glob imported.
+
+use crate::{
+    si_system::{dimensions::*, SIDimensionless},
+    Quantity,
+};
+use extended_typenum::op;
+
+/// Speed of light in vacuum, c = 299 792 458 m/s.
+pub const SPEED_OF_LIGHT: Quantity<f64, Velocity> = Quantity::from_work(299_792_458.0);
+
+/// Standard acceleration of gravity, g₀ = 9.806 65 m/s².
+pub const STANDARD_GRAVITY: Quantity<f64, Acceleration> = Quantity::from_work(9.806_65);
+
+/// Planck constant, h = 6.626 070 15 × 10⁻³⁴ J⋅s.
+pub const PLANCK: Quantity<f64, Action> = Quantity::from_work(6.626_070_15e-34);
+
+/// Boltzmann constant, k = 1.380 649 × 10⁻²³ J/K.
+pub const BOLTZMANN: Quantity<f64, HeatCapacity> = Quantity::from_work(1.380_649e-23);
+
+/// Elementary charge, e = 1.602 176 634 × 10⁻¹⁹ C.
+pub const ELEMENTARY_CHARGE: Quantity<f64, ElectricCharge> = Quantity::from_work(1.602_176_634e-19);
+
+/// Avogadro constant, N_A = 6.022 140 76 × 10²³ mol⁻¹.
+pub const AVOGADRO: Quantity<f64, op!(SIDimensionless / Substance)> = Quantity::from_work(6.022_140_76e23);
+
+/// Molar gas constant, R = N_A ⋅ k = 8.314 462 618... J/(mol⋅K).
+///
+/// ```
+/// use rust_units::Quantity;
+/// use rust_units::si_system::{constants::*, dimensions::MolarHeatCapacity};
+///
+/// let r: Quantity<f64, MolarHeatCapacity> = AVOGADRO * BOLTZMANN;
+///
+/// assert!((r.get_work() - GAS_CONSTANT.get_work()).abs() < 1e-12);
+/// ```
+pub const GAS_CONSTANT: Quantity<f64, MolarHeatCapacity> = Quantity::from_work(8.314_462_618_153_24);
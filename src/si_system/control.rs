@@ -0,0 +1,103 @@
+//! Controllers whose gains have the dimensions that make their output dimensionally correct.
+
+use core::{
+    marker::PhantomData,
+    ops::{Div, Mul},
+};
+
+use derive_where::derive_where;
+
+use crate::{si_system::dimensions::Time, Dimension, Quantity};
+
+/// A PID controller, turning an error of dimension `E` into a command of dimension `O`.
+///
+/// The gains are checked at compile time when the controller is created: with `T` a [`Time`],
+/// `kp * E`, `ki * E * T` and `kd * E / T` must all be of dimension `O`.
+///
+/// The integral term uses the rectangle rule, and the derivative term is zero on the first update
+/// (the previous error is unknown).
+///
+/// ```
+/// use rust_units::{Quantity, Dimension, Unit};
+/// use rust_units::si_system::{control::Pid, dimensions::*, units::*};
+/// use extended_typenum::op;
+///
+/// // Position error to force.
+/// let mut pid = Pid::<Length, Force>::new(
+///     Quantity::<f64, op!(Force / Length)>::from_work(2.0),
+///     Quantity::<f64, op!(Force / Length / Time)>::from_work(0.25),
+///     Quantity::<f64, op!(Force * Time / Length)>::from_work(0.5),
+/// );
+///
+/// let dt = SECOND.build(0.5);
+///
+/// // Step error of 1 m.
+/// assert_eq!(pid.update(METER.build(1.0), dt), NEWTON.build(2.125));
+/// assert_eq!(pid.update(METER.build(1.0), dt), NEWTON.build(2.25));
+///
+/// // The error vanishes: only the integral and derivative terms remain.
+/// assert_eq!(pid.update(METER.build(0.0), dt), NEWTON.build(0.25 - 1.0));
+/// ```
+///
+/// Gains of the wrong dimension do not compile:
+///
+/// ```compile_fail
+/// use rust_units::{Quantity, Dimension};
+/// use rust_units::si_system::{control::Pid, dimensions::*};
+/// use extended_typenum::op;
+///
+/// let pid = Pid::<Length, Force>::new(
+///     Quantity::<f64, op!(Force / Length)>::from_work(2.0),
+///     Quantity::<f64, op!(Force / Length)>::from_work(0.25),
+///     Quantity::<f64, op!(Force * Time / Length)>::from_work(0.5),
+/// );
+/// ```
+#[derive_where(Debug, Clone, Copy, PartialEq)]
+pub struct Pid<E, O> {
+    // The gains and the state, in work unit.
+    kp: f64,
+    ki: f64,
+    kd: f64,
+    integral: f64,
+    previous_error: Option<f64>,
+    dimensions: PhantomData<(E, O)>,
+}
+
+impl<E: Dimension, O: Dimension> Pid<E, O> {
+    /// Creates a new controller from its gains.
+    pub fn new<Kp, Ki, Kd>(kp: Quantity<f64, Kp>, ki: Quantity<f64, Ki>, kd: Quantity<f64, Kd>) -> Self
+    where
+        Kp: Dimension,
+        Ki: Dimension,
+        Kd: Dimension,
+        E: Mul<Kp, Output = O> + Mul<Time> + Div<Time>,
+        <E as Mul<Time>>::Output: Mul<Ki, Output = O>,
+        <E as Div<Time>>::Output: Mul<Kd, Output = O>,
+    {
+        Self {
+            kp: kp.get_work(),
+            ki: ki.get_work(),
+            kd: kd.get_work(),
+            integral: 0.0,
+            previous_error: None,
+            dimensions: PhantomData,
+        }
+    }
+
+    /// Updates the controller with the current error, `dt` after the previous update, and returns the command.
+    pub fn update(&mut self, error: Quantity<f64, E>, dt: Quantity<f64, Time>) -> Quantity<f64, O> {
+        let (error, dt) = (error.get_work(), dt.get_work());
+
+        self.integral += error * dt;
+        let derivative = self.previous_error.map_or(0.0, |previous| (error - previous) / dt);
+        self.previous_error = Some(error);
+
+        Quantity::from_work(self.kp * error + self.ki * self.integral + self.kd * derivative)
+    }
+
+    /// Resets the integral term and forgets the previous error.
+    pub fn reset(&mut self) {
+        self.integral = 0.0;
+        self.previous_error = None;
+    }
+}
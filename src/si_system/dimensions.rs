@@ -1,4 +1,20 @@
 //! SI Dimension system and usual composite dimensions.
+//!
+//! The composite dimensions are type aliases built from the base dimensions, so they can be used directly
+//! in the type of a [`Quantity`](crate::Quantity), and the results of the operations have the expected alias type:
+//!
+//! ```
+//! use rust_units::{Quantity, Dimension};
+//! use rust_units::si_system::dimensions::*;
+//!
+//! let v: Quantity<f64, Velocity> = Length::from_work(10.0) / Time::from_work(2.0);
+//! let f: Quantity<f64, Force> = Mass::from_work(2.0) * (v / Time::from_work(1.0));
+//! let e: Quantity<f64, Energy> = f * Length::from_work(3.0);
+//! let q: Quantity<f64, Charge> = Current::from_work(2.0) * Time::from_work(3.0);
+//! let u: Quantity<f64, Voltage> = e / q;
+//!
+//! assert_eq!(u.get_work(), 5.0);
+//! ```
 
 use extended_typenum::op;
 
@@ -79,6 +95,8 @@ pub type Power = op!(Energy / Time);
 pub type RadiantFlux = Power;
 /// [`Dimension`](crate::Dimension) for electric charge ([`Current`]⋅[`Time`], [`Capacitance`]⋅[`Voltage`] = [`Time`]⋅[`Current`]).
 pub type ElectricCharge = op!(Current * Time);
+/// Same as [`ElectricCharge`]
+pub type Charge = ElectricCharge;
 /// [`Dimension`](crate::Dimension) for voltage, electric potential, or electromotive force ([`Energy`]/[`ElectricCharge`], [`Power`]/[`Current`] = [`Mass`]⋅[`Length`]²⋅[`Time`]⁻³⋅[`Current`]⁻¹).
 pub type Voltage = op!(Energy / ElectricCharge);
 /// Same as [`Voltage`]
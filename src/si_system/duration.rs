@@ -0,0 +1,68 @@
+//! Conversions between [`Time`] quantities and [`chrono::TimeDelta`] (also known as `chrono::Duration`).
+//!
+//! Only available with the `chrono` feature.
+//!
+//! A [`TimeDelta`] can always be converted into a time quantity. The reverse conversion is fallible,
+//! as the quantity may be `NaN`, infinite, or out of the range of [`TimeDelta`].
+//! It is rounded to the nearest nanosecond, which is the resolution of [`TimeDelta`].
+//!
+//! ```
+//! use rust_units::{Quantity, Unit};
+//! use rust_units::si_system::{dimensions::Time, units::*};
+//! use chrono::TimeDelta;
+//!
+//! let delta = TimeDelta::milliseconds(-1500);
+//! let time: Quantity<f64, Time> = delta.into();
+//!
+//! assert_eq!(time, SECOND.build(-1.5));
+//! assert_eq!(TimeDelta::try_from(time), Ok(delta));
+//!
+//! assert!(TimeDelta::try_from(SECOND.build(f64::NAN)).is_err());
+//! assert!(TimeDelta::try_from(SECOND.build(1e300)).is_err());
+//! ```
+
+use std::fmt::Display;
+
+use chrono::TimeDelta;
+
+use crate::{si_system::dimensions::Time, Quantity};
+
+impl From<TimeDelta> for Quantity<f64, Time> {
+    fn from(delta: TimeDelta) -> Self {
+        // Both parts have the sign of the delta.
+        Quantity::from_work(delta.num_seconds() as f64 + delta.subsec_nanos() as f64 * 1e-9)
+    }
+}
+
+/// Error returned when a time quantity can not be converted into a [`TimeDelta`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeDeltaOutOfRange;
+
+impl Display for TimeDeltaOutOfRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "time quantity out of the range of chrono::TimeDelta")
+    }
+}
+
+impl std::error::Error for TimeDeltaOutOfRange {}
+
+impl TryFrom<Quantity<f64, Time>> for TimeDelta {
+    type Error = TimeDeltaOutOfRange;
+
+    fn try_from(time: Quantity<f64, Time>) -> Result<Self, Self::Error> {
+        let seconds = time.get_work();
+        if !seconds.is_finite() || seconds.abs() >= i64::MAX as f64 {
+            return Err(TimeDeltaOutOfRange);
+        }
+
+        // TimeDelta::new expects non negative nanoseconds, so the seconds are rounded down.
+        let mut secs = seconds.floor();
+        let mut nanos = ((seconds - secs) * 1e9).round();
+        if nanos >= 1e9 {
+            secs += 1.0;
+            nanos -= 1e9;
+        }
+
+        TimeDelta::new(secs as i64, nanos as u32).ok_or(TimeDeltaOutOfRange)
+    }
+}
@@ -2,11 +2,11 @@
 //!
 //! You should not have to interact with them directly for standard use of the si system (or even your own variants).
 
-use crate::si_system::SIDimension;
+use crate::{si_system::SIDimension, DimSqrt, Dimension};
 use derive_where::derive_where;
-use extended_typenum::TypeDisplay;
+use extended_typenum::{rational, TypeDisplay, P1, U2};
 use num_traits::{Inv, MulAdd, MulAddAssign, Pow};
-use std::{marker::PhantomData, ops::*};
+use core::{marker::PhantomData, ops::*};
 
 /// The dimensionless dimension, used in [`SIDimension`].
 ///
@@ -43,7 +43,7 @@ pub struct SIDim<I, O, E, Rest> {
 }
 
 pub mod helpers;
-use helpers::{SimplH, SimplifyHead};
+use helpers::{IntegerExponents, SimplH, SimplifyHead};
 
 mod macros;
 use crate::{si_impl_bin_op, si_impl_tern_op, si_impl_un_op};
@@ -193,17 +193,27 @@ where
     }
 }
 
+/// The square root is only defined when all the exponents are even, so that the exponents of the result are integers.
+impl<D, R> DimSqrt for SIDimension<D>
+where
+    SIDimension<D>: Dimension + Pow<rational!(P1, U2), Output = SIDimension<R>>,
+    SIDimension<R>: Dimension,
+    R: IntegerExponents,
+{
+    type Output = SIDimension<R>;
+}
+
 impl<D> TypeDisplay for SIDimension<D>
 where
     D: TypeDisplay,
 {
-    fn fmt(f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         D::fmt(f)
     }
 }
 
 impl TypeDisplay for Dimensionless {
-    fn fmt(f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "")
     }
 }
@@ -213,7 +223,7 @@ where
     I: TypeDisplay,
     E: TypeDisplay,
 {
-    fn fmt(f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         I::fmt(f)?;
         E::fmt(f)
     }
@@ -225,7 +235,7 @@ where
     E: TypeDisplay,
     SIDim<I2, O2, E2, Rest2>: TypeDisplay,
 {
-    fn fmt(f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         I::fmt(f)?;
         E::fmt(f)?;
         write!(f, ".")?;
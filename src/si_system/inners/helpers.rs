@@ -5,16 +5,16 @@
 //!
 //! It is not recommended to implement these traits and use these structs directly unless you know what you are doing.
 
-use std::ops::BitAnd;
+use core::ops::BitAnd;
 
 use extended_typenum::{
-    type_operators_extended::IsZero, And, Cmp, Equal, False, GetZero, Greater, If, IsNull, Less,
-    True, TypeIf, UInt, ZeroOf, U0,
+    type_operators_extended::IsZero, And, Cmp, CrossInt, Equal, False, GetZero, Greater, If, Integer,
+    IsNull, Less, True, TypeIf, UInt, ZeroOf, U0,
 };
 
 use crate::{
     si_system::{
-        inners::{helpers::common_heads_helpers::CompHeads, Dimensionless, SIDim},
+        inners::{helpers::common_heads_helpers::CompHeads, Dimensionless, SIDim, SIExponent},
         SIDimension,
     },
     Dimension,
@@ -224,6 +224,19 @@ where
 
 impl<D: Valid> Dimension for SIDimension<D> {}
 
+// ----------------------------------------------
+// Integer exponents
+// ----------------------------------------------
+
+/// Indicates that all the exponents of the implementor dimension are integers.
+///
+/// Used to implement [`DimSqrt`](crate::DimSqrt) only for the dimensions whose exponents are all even.
+pub trait IntegerExponents {}
+
+impl IntegerExponents for Dimensionless {}
+
+impl<I, O, E: Integer, Rest: IntegerExponents> IntegerExponents for SIDim<I, O, SIExponent<CrossInt<E>>, Rest> {}
+
 // ----------------------------------------------
 // Common head
 // ----------------------------------------------
@@ -244,7 +257,7 @@ pub type ComD2<D1, D2> = <D1 as CommonHeads<D2>>::Other;
 
 pub mod common_heads_helpers {
     //! Helper struct and trait for implementation of [`CommonHeads`].
-    use std::marker::PhantomData;
+    use core::marker::PhantomData;
 
     use extended_typenum::Compare;
 
@@ -1,5 +1,5 @@
-use std::marker::PhantomData;
-use std::ops::*;
+use core::marker::PhantomData;
+use core::ops::*;
 
 use extended_typenum::{op, Bit, GetZero, IsEqual, IsNull, IsZero, TypeDisplay, ZeroOf, P1};
 use num_traits::{Inv, MulAdd, MulAddAssign, Pow};
@@ -210,7 +210,7 @@ where
     E: IsEqual<P1>,
     <E as IsEqual<P1>>::Output: Bit,
 {
-    fn fmt(f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         if <<E as IsEqual<P1>>::Output as Bit>::BOOL {
             return write!(f, "");
         }
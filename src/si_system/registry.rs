@@ -0,0 +1,315 @@
+//! Registry of the predefined [`units`](super::units), tagged with the [`UnitFamily`] they belong to.
+//!
+//! The registry is used to pick a unit at runtime, for example to display a [`Quantity`] in a readable way
+//! with [`to_human_readable_in_family`](Quantity::to_human_readable_in_family), or to parse a [`Quantity`]
+//! from a string with its [`FromStr`] implementation.
+
+use std::{fmt::Display, marker::PhantomData, str::FromStr, sync::OnceLock};
+
+use derive_where::derive_where;
+
+use crate::{
+    si_system::{
+        runtime::{ConversionTable, DimensionError, DynUnit, RuntimeDimension, ToRuntimeDimension},
+        units::*,
+    },
+    Quantity,
+};
+
+/// The family of a unit, i.e. the system of units it usually comes with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UnitFamily {
+    /// The metric system: the SI units and their prefixed versions.
+    Metric,
+    /// The imperial and US customary units, such as the [`FOOT`] or the [`MILE`].
+    Imperial,
+}
+
+/// A unit of the registry.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RegisteredUnit {
+    /// The symbol of the unit, including its prefix.
+    pub symbol: &'static str,
+    /// The family the unit belongs to.
+    pub family: UnitFamily,
+    /// The unit itself.
+    pub unit: DynUnit,
+}
+
+impl RegisteredUnit {
+    fn new<U>(symbol: &'static str, family: UnitFamily, unit: &U) -> Self
+    where
+        U: Unit<f64>,
+        U::Dimension: ToRuntimeDimension,
+    {
+        Self {
+            symbol,
+            family,
+            unit: DynUnit::from_unit(unit),
+        }
+    }
+}
+
+/// Returns all the registered units.
+///
+/// ```
+/// use rust_units::si_system::registry::*;
+///
+/// let foot = registered_units().iter().find(|u| u.symbol == "ft").unwrap();
+///
+/// assert_eq!(foot.family, UnitFamily::Imperial);
+/// assert!((foot.unit.prop_constant() - 0.3048).abs() < 1e-15);
+/// ```
+pub fn registered_units() -> &'static [RegisteredUnit] {
+    static UNITS: OnceLock<Vec<RegisteredUnit>> = OnceLock::new();
+    UNITS.get_or_init(|| {
+        use UnitFamily::*;
+        vec![
+            // Length
+            RegisteredUnit::new("mm", Metric, &MILLIMETER),
+            RegisteredUnit::new("cm", Metric, &CENTIMETER),
+            RegisteredUnit::new("m", Metric, &METER),
+            RegisteredUnit::new("km", Metric, &KILOMETER),
+            RegisteredUnit::new("in", Imperial, &INCH),
+            RegisteredUnit::new("ft", Imperial, &FOOT),
+            RegisteredUnit::new("yd", Imperial, &YARD),
+            RegisteredUnit::new("mi", Imperial, &MILE),
+            // Mass
+            RegisteredUnit::new("mg", Metric, &KILOGRAM.set_milli_prefix()),
+            RegisteredUnit::new("g", Metric, &GRAM),
+            RegisteredUnit::new("kg", Metric, &KILOGRAM),
+            // Time
+            RegisteredUnit::new("s", Metric, &SECOND),
+            RegisteredUnit::new("min", Metric, &MINUTE),
+            RegisteredUnit::new("h", Metric, &HOUR),
+            RegisteredUnit::new("d", Metric, &DAY),
+            // Other base units
+            RegisteredUnit::new("A", Metric, &AMPERE),
+            RegisteredUnit::new("K", Metric, &KELVIN),
+            RegisteredUnit::new("mol", Metric, &MOLE),
+            RegisteredUnit::new("cd", Metric, &CANDELA),
+            RegisteredUnit::new("rad", Metric, &RADIAN),
+            RegisteredUnit::new("sr", Metric, &STERADIAN),
+            // Derived units
+            RegisteredUnit::new("Hz", Metric, &HERTZ),
+            RegisteredUnit::new("N", Metric, &NEWTON),
+            RegisteredUnit::new("Pa", Metric, &PASCAL),
+            RegisteredUnit::new("J", Metric, &JOULE),
+            RegisteredUnit::new("W", Metric, &WATT),
+            RegisteredUnit::new("C", Metric, &COULOMB),
+            RegisteredUnit::new("V", Metric, &VOLT),
+        ]
+    })
+}
+
+/// Returns a [`ConversionTable`] containing all the [`registered_units`], indexed by their symbol.
+///
+/// It is the table used to parse quantities with [`FromStr`].
+pub fn registered_table() -> &'static ConversionTable {
+    static TABLE: OnceLock<ConversionTable> = OnceLock::new();
+    TABLE.get_or_init(|| {
+        let mut table = ConversionTable::new();
+        for unit in registered_units() {
+            table.insert(unit.symbol, unit.unit);
+        }
+        table
+    })
+}
+
+/// Error returned when parsing a [`Quantity`] fails.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseQuantityError {
+    /// The number could not be parsed.
+    MalformedNumber(String),
+    /// The unit expression could not be parsed.
+    MalformedUnit(String),
+    /// A symbol of the unit expression is not a registered unit.
+    UnknownUnit(String),
+    /// The unit does not have the dimension of the quantity.
+    DimensionMismatch {
+        /// The dimension of the quantity.
+        expected: RuntimeDimension,
+        /// The dimension of the unit.
+        found: RuntimeDimension,
+    },
+}
+
+impl Display for ParseQuantityError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseQuantityError::MalformedNumber(number) => write!(f, "invalid number: '{}'", number),
+            ParseQuantityError::MalformedUnit(message) => write!(f, "invalid unit: {}", message),
+            ParseQuantityError::UnknownUnit(unit) => write!(f, "unknown unit: '{}'", unit),
+            ParseQuantityError::DimensionMismatch { expected, found } => {
+                write!(f, "dimension mismatch: expected [{}], found [{}]", expected, found)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseQuantityError {}
+
+impl From<DimensionError> for ParseQuantityError {
+    fn from(error: DimensionError) -> Self {
+        match error {
+            DimensionError::Mismatch { expected, found } => ParseQuantityError::DimensionMismatch { expected, found },
+            DimensionError::UnknownUnit(unit) => ParseQuantityError::UnknownUnit(unit),
+            DimensionError::Malformed(message) => ParseQuantityError::MalformedUnit(message),
+            error @ DimensionError::ExponentOverflow => ParseQuantityError::MalformedUnit(error.to_string()),
+        }
+    }
+}
+
+/// Parses a quantity written as a number followed by a unit expression made of [`registered_units`],
+/// such as `5 km` or `9.81 m/s^2` (see [`ConversionTable::parse_unit`] for the syntax of the expressions).
+///
+/// ```
+/// use rust_units::{Dimension, Quantity, Unit};
+/// use rust_units::si_system::{dimensions::*, registry::ParseQuantityError, units::*};
+///
+/// assert_eq!("5 km".parse::<Quantity<f64, Length>>(), Ok(KILOMETER.build(5.0)));
+/// assert_eq!("9.81 m/s^2".parse::<Quantity<f64, Acceleration>>(), Ok(Acceleration::from_work(9.81)));
+///
+/// assert!(matches!("5 s".parse::<Quantity<f64, Length>>(), Err(ParseQuantityError::DimensionMismatch { .. })));
+/// assert!(matches!("5 parsec".parse::<Quantity<f64, Length>>(), Err(ParseQuantityError::UnknownUnit(_))));
+/// assert!(matches!("five km".parse::<Quantity<f64, Length>>(), Err(ParseQuantityError::MalformedNumber(_))));
+/// assert!(matches!("5 km/".parse::<Quantity<f64, Length>>(), Err(ParseQuantityError::MalformedUnit(_))));
+/// ```
+impl<D: ToRuntimeDimension> FromStr for Quantity<f64, D> {
+    type Err = ParseQuantityError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (value, unit) = s.split_once(char::is_whitespace).unwrap_or((s, "1"));
+        let value = value
+            .parse::<f64>()
+            .map_err(|_| ParseQuantityError::MalformedNumber(value.to_string()))?;
+        Ok(registered_table().parse_unit(unit)?.try_build(value)?)
+    }
+}
+
+/// Parser for a column of a table of quantities, such as a CSV file, whose unit is given once in the header.
+///
+/// The header is a name followed by a unit expression in brackets, such as `speed [km/h]`.
+/// The unit is made of [`registered_units`], like for the [`FromStr`] implementation of [`Quantity`],
+/// and its dimension is checked against `D` once, when parsing the header.
+///
+/// ```
+/// use rust_units::si_system::{dimensions::*, registry::*};
+///
+/// let speed = ColumnParser::<Velocity>::from_header("speed [km/h]").unwrap();
+///
+/// assert_eq!(speed.name(), "speed");
+/// let cells: Result<Vec<_>, _> = ["36", " 72.0 ", "0"].into_iter().map(|cell| speed.parse_cell(cell)).collect();
+/// // In work unit, m/s.
+/// let values: Vec<f64> = cells.unwrap().into_iter().map(|v| v.get_work()).collect();
+/// assert!(values.iter().zip([10.0, 20.0, 0.0]).all(|(v, e)| (v - e).abs() < 1e-12));
+///
+/// assert!(matches!(speed.parse_cell("fast"), Err(ParseQuantityError::MalformedNumber(_))));
+/// assert!(matches!(
+///     ColumnParser::<Velocity>::from_header("duration [h]"),
+///     Err(ParseQuantityError::DimensionMismatch { .. })
+/// ));
+/// assert!(matches!(
+///     ColumnParser::<Velocity>::from_header("speed"),
+///     Err(ParseQuantityError::MalformedUnit(_))
+/// ));
+/// ```
+#[derive_where(Debug, Clone, PartialEq)]
+pub struct ColumnParser<D> {
+    name: String,
+    unit: DynUnit,
+    dimension: PhantomData<D>,
+}
+
+impl<D: ToRuntimeDimension> ColumnParser<D> {
+    /// Creates a parser from the header of the column, as `name [unit]`.
+    pub fn from_header(header: &str) -> Result<Self, ParseQuantityError> {
+        let malformed = || ParseQuantityError::MalformedUnit(format!("expected 'name [unit]', found '{}'", header));
+        let (name, unit) = header.split_once('[').ok_or_else(malformed)?;
+        let unit = unit.trim_end().strip_suffix(']').ok_or_else(malformed)?;
+
+        let unit = registered_table().parse_unit(unit)?;
+        unit.dimension().check(D::runtime_dimension())?;
+        Ok(Self {
+            name: name.trim().to_string(),
+            unit,
+            dimension: PhantomData,
+        })
+    }
+
+    /// Returns the name of the column, without its unit.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the unit of the column.
+    pub fn unit(&self) -> DynUnit {
+        self.unit
+    }
+
+    /// Parses a cell of the column, holding a number in the unit of the column.
+    pub fn parse_cell(&self, cell: &str) -> Result<Quantity<f64, D>, ParseQuantityError> {
+        let value = cell
+            .trim()
+            .parse::<f64>()
+            .map_err(|_| ParseQuantityError::MalformedNumber(cell.to_string()))?;
+        Ok(self.unit.try_build(value)?)
+    }
+}
+
+impl<D: ToRuntimeDimension> Quantity<f64, D> {
+    /// Formats the quantity in the registered unit of the given [`UnitFamily`] that is the most readable.
+    ///
+    /// The chosen unit is the largest one in which the absolute value is at least 1, or the smallest one
+    /// if there is none. The value is rounded to 3 decimals, and trailing zeros are removed.
+    ///
+    /// If no unit of the family has the dimension of the quantity, it is formatted in work unit.
+    ///
+    /// ```
+    /// use rust_units::Unit;
+    /// use rust_units::si_system::{registry::UnitFamily, units::*};
+    ///
+    /// let distance = MILE.build(1.0);
+    ///
+    /// assert_eq!(distance.to_human_readable_in_family(UnitFamily::Metric), "1.609 km");
+    /// assert_eq!(distance.to_human_readable_in_family(UnitFamily::Imperial), "1 mi");
+    ///
+    /// let length = METER.build(0.5);
+    ///
+    /// assert_eq!(length.to_human_readable_in_family(UnitFamily::Metric), "50 cm");
+    /// assert_eq!(length.to_human_readable_in_family(UnitFamily::Imperial), "1.64 ft");
+    ///
+    /// // No time unit is imperial.
+    /// assert_eq!(SECOND.build(2.0).to_human_readable_in_family(UnitFamily::Imperial), "2 s");
+    /// ```
+    pub fn to_human_readable_in_family(self, family: UnitFamily) -> String {
+        let value = self.get_work();
+        let dimension = D::runtime_dimension();
+
+        let mut candidates: Vec<_> = registered_units()
+            .iter()
+            .filter(|u| u.family == family && u.unit.dimension() == dimension)
+            .collect();
+        candidates.sort_by(|a, b| a.unit.prop_constant().total_cmp(&b.unit.prop_constant()));
+
+        let chosen = candidates
+            .iter()
+            .rev()
+            .find(|u| (value / u.unit.prop_constant()).abs() >= 1.0)
+            .or(candidates.first());
+
+        let (value, symbol) = match chosen {
+            Some(u) => (value / u.unit.prop_constant(), u.symbol.to_string()),
+            None => (value, dimension.to_string()),
+        };
+
+        let value = format!("{value:.3}");
+        let value = value.trim_end_matches('0').trim_end_matches('.');
+        if symbol.is_empty() {
+            value.to_string()
+        } else {
+            format!("{value} {symbol}")
+        }
+    }
+}
@@ -0,0 +1,1539 @@
+//! Runtime representation of the dimensions of the SI system.
+//!
+//! The dimensions of the [`SISystemWithAngles`](super::SISystemWithAngles) are checked at compile time.
+//! This module provides a way to inspect them at runtime with [`RuntimeDimension`], and units whose
+//! dimension is only known at runtime ([`DynUnit`]), for example when they are selected from a registry.
+//!
+//! Since the dimension of a [`DynUnit`] is not known at compile time, using it on a [`Quantity`] is fallible.
+
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Display},
+    ops::{Div, Mul},
+    str::FromStr,
+};
+
+use extended_typenum::{CrossInt, Integer};
+use num_traits::Inv;
+
+use crate::{
+    si_system::{
+        dimensions::*,
+        inners::{Dimensionless, SIDim, SIExponent},
+        SIDimension,
+    },
+    Dimension, Quantity, Unit, WorkPropUnit,
+};
+
+/// The base dimensions of the [`SISystemWithAngles`](super::SISystemWithAngles).
+///
+/// They are ordered in the same way as in the type-level dimensions.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SIBaseDimension {
+    /// Base dimension of [`Time`]. Symbol: s.
+    Time,
+    /// Base dimension of [`Length`]. Symbol: m.
+    Length,
+    /// Base dimension of [`Mass`]. Symbol: kg.
+    Mass,
+    /// Base dimension of [`Current`]. Symbol: A.
+    Current,
+    /// Base dimension of [`Temperature`]. Symbol: K.
+    Temperature,
+    /// Base dimension of [`Substance`]. Symbol: mol.
+    Substance,
+    /// Base dimension of [`LightIntensity`]. Symbol: cd.
+    LightIntensity,
+    /// Base dimension of [`Angle`]. Symbol: rad.
+    Angle,
+    /// Base dimension of [`SolidAngle`]. Symbol: sr.
+    SolidAngle,
+}
+
+impl SIBaseDimension {
+    /// Number of base dimensions.
+    pub const COUNT: usize = 9;
+
+    /// All the base dimensions, in order.
+    pub const ALL: [SIBaseDimension; Self::COUNT] = [
+        SIBaseDimension::Time,
+        SIBaseDimension::Length,
+        SIBaseDimension::Mass,
+        SIBaseDimension::Current,
+        SIBaseDimension::Temperature,
+        SIBaseDimension::Substance,
+        SIBaseDimension::LightIntensity,
+        SIBaseDimension::Angle,
+        SIBaseDimension::SolidAngle,
+    ];
+
+    /// Returns the position of the base dimension in [`ALL`](SIBaseDimension::ALL).
+    pub const fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Returns the symbol of the work unit of the base dimension (e.g. `m` for `Length`).
+    pub fn symbol(self) -> &'static str {
+        match self {
+            SIBaseDimension::Time => "s",
+            SIBaseDimension::Length => "m",
+            SIBaseDimension::Mass => "kg",
+            SIBaseDimension::Current => "A",
+            SIBaseDimension::Temperature => "K",
+            SIBaseDimension::Substance => "mol",
+            SIBaseDimension::LightIntensity => "cd",
+            SIBaseDimension::Angle => "rad",
+            SIBaseDimension::SolidAngle => "sr",
+        }
+    }
+
+    /// Returns the base dimension whose work unit has the given symbol (e.g. `Length` for `m`).
+    pub fn from_symbol(symbol: &str) -> Option<SIBaseDimension> {
+        Self::ALL.into_iter().find(|base| base.symbol() == symbol)
+    }
+}
+
+/// Trait implemented by the IDs of the base dimensions (`I` parameter of [`SIDim`]).
+///
+/// It associates the ID to the corresponding [`SIBaseDimension`].
+pub trait TypeBaseDimension {
+    /// The corresponding [`SIBaseDimension`].
+    const BASE: SIBaseDimension;
+}
+
+macro_rules! impl_type_base_dimension {
+    ($($ID:ident => $Base:ident),* $(,)?) => {
+        $(impl TypeBaseDimension for $ID {
+            const BASE: SIBaseDimension = SIBaseDimension::$Base;
+        })*
+    };
+}
+
+impl_type_base_dimension! {
+    TimeID => Time,
+    LengthID => Length,
+    MassID => Mass,
+    CurrentID => Current,
+    TemperatureID => Temperature,
+    SubstanceID => Substance,
+    LightIntensityID => LightIntensity,
+    AngleID => Angle,
+    SolidAngleID => SolidAngle,
+}
+
+/// Dimension of the SI system, known at runtime.
+///
+/// It stores the exponent of each [`SIBaseDimension`].
+/// It is obtained from a type-level dimension with the [`ToRuntimeDimension`] trait.
+///
+/// ```
+/// use rust_units::si_system::{dimensions::*, runtime::*};
+///
+/// let speed = Speed::runtime_dimension();
+///
+/// assert_eq!(speed.exponent(SIBaseDimension::Length), 1);
+/// assert_eq!(speed.exponent(SIBaseDimension::Time), -1);
+/// assert_eq!(speed, Length::runtime_dimension() / Time::runtime_dimension());
+/// assert_eq!(format!("{speed}"), "s^-1.m");
+/// ```
+///
+/// The exponents are `i8`s. The operators panic if one overflows, the `checked_*` methods return `None` instead:
+///
+/// ```should_panic
+/// use rust_units::si_system::{dimensions::*, runtime::*};
+///
+/// let huge = Length::runtime_dimension().powi(100);
+///
+/// assert_eq!(huge.checked_mul(huge), None);
+/// let _ = huge * huge;
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RuntimeDimension {
+    exponents: [i8; SIBaseDimension::COUNT],
+}
+
+impl RuntimeDimension {
+    /// The dimensionless dimension (all exponents are zero).
+    pub const DIMENSIONLESS: Self = Self::from_exponents([0; SIBaseDimension::COUNT]);
+
+    /// Creates a new [`RuntimeDimension`] from the exponents of the base dimensions,
+    /// given in the order of [`SIBaseDimension::ALL`].
+    pub const fn from_exponents(exponents: [i8; SIBaseDimension::COUNT]) -> Self {
+        Self { exponents }
+    }
+
+    /// Creates the [`RuntimeDimension`] of a base dimension (its exponent is one, the other ones are zero).
+    pub const fn base(base: SIBaseDimension) -> Self {
+        let mut exponents = [0; SIBaseDimension::COUNT];
+        exponents[base.index()] = 1;
+        Self { exponents }
+    }
+
+    /// Returns the exponents of the base dimensions, in the order of [`SIBaseDimension::ALL`].
+    pub const fn exponents(&self) -> [i8; SIBaseDimension::COUNT] {
+        self.exponents
+    }
+
+    /// Returns the exponent of the given base dimension.
+    pub const fn exponent(&self, base: SIBaseDimension) -> i8 {
+        self.exponents[base.index()]
+    }
+
+    /// Returns `true` if all the exponents are zero.
+    pub fn is_dimensionless(&self) -> bool {
+        self.exponents.iter().all(|e| *e == 0)
+    }
+
+    /// Raises the dimension to an integer power (multiplies all the exponents by `n`).
+    ///
+    /// **Panics** if an exponent overflows, see [`checked_powi`](RuntimeDimension::checked_powi).
+    pub fn powi(self, n: i8) -> Self {
+        self.checked_powi(n).expect("dimension exponent overflow")
+    }
+
+    /// Multiplies two dimensions (adds their exponents), or returns `None` if an exponent overflows.
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let mut exponents = self.exponents;
+        for (e, r) in exponents.iter_mut().zip(rhs.exponents) {
+            *e = e.checked_add(r)?;
+        }
+        Some(Self { exponents })
+    }
+
+    /// Divides two dimensions (subtracts their exponents), or returns `None` if an exponent overflows.
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        let mut exponents = self.exponents;
+        for (e, r) in exponents.iter_mut().zip(rhs.exponents) {
+            *e = e.checked_sub(r)?;
+        }
+        Some(Self { exponents })
+    }
+
+    /// Raises the dimension to an integer power (multiplies all the exponents by `n`),
+    /// or returns `None` if an exponent overflows.
+    pub fn checked_powi(self, n: i8) -> Option<Self> {
+        let mut exponents = self.exponents;
+        for e in exponents.iter_mut() {
+            *e = e.checked_mul(n)?;
+        }
+        Some(Self { exponents })
+    }
+
+    /// Inverts the dimension (negates its exponents), or returns `None` if an exponent overflows
+    /// (an exponent of `-128` has no opposite).
+    ///
+    /// ```
+    /// use rust_units::si_system::{dimensions::*, runtime::*};
+    ///
+    /// assert_eq!(Time::runtime_dimension().checked_inv(), Some(Frequency::runtime_dimension()));
+    /// assert_eq!("m^-128".parse::<RuntimeDimension>().unwrap().checked_inv(), None);
+    /// ```
+    pub fn checked_inv(self) -> Option<Self> {
+        let mut exponents = self.exponents;
+        for e in exponents.iter_mut() {
+            *e = e.checked_neg()?;
+        }
+        Some(Self { exponents })
+    }
+
+    /// Checks that this dimension is equal to the `expected` one.
+    pub fn check(self, expected: RuntimeDimension) -> Result<(), DimensionError> {
+        if self == expected {
+            Ok(())
+        } else {
+            Err(DimensionError::Mismatch {
+                expected,
+                found: self,
+            })
+        }
+    }
+}
+
+impl Mul for RuntimeDimension {
+    type Output = Self;
+
+    /// Multiplies two dimensions (adds their exponents).
+    ///
+    /// **Panics** if an exponent overflows, see [`checked_mul`](RuntimeDimension::checked_mul).
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.checked_mul(rhs).expect("dimension exponent overflow")
+    }
+}
+
+impl Div for RuntimeDimension {
+    type Output = Self;
+
+    /// Divides two dimensions (subtracts their exponents).
+    ///
+    /// **Panics** if an exponent overflows, see [`checked_div`](RuntimeDimension::checked_div).
+    fn div(self, rhs: Self) -> Self::Output {
+        self.checked_div(rhs).expect("dimension exponent overflow")
+    }
+}
+
+impl Inv for RuntimeDimension {
+    type Output = Self;
+
+    /// Inverts the dimension (negates its exponents).
+    ///
+    /// **Panics** if an exponent overflows, see [`checked_inv`](RuntimeDimension::checked_inv).
+    fn inv(self) -> Self::Output {
+        self.checked_inv().expect("dimension exponent overflow")
+    }
+}
+
+/// Same format as the [`Display`] implementation of [`Quantity`]: `s^-1.m` for a speed.
+///
+/// The alternate flag (`{:#}`) gives the conventional notation instead, with superscript exponents
+/// and the base dimensions in the order kg, m, s, A, K, mol, cd, rad, sr:
+///
+/// ```
+/// use rust_units::si_system::{dimensions::*, runtime::*};
+///
+/// let force = Force::runtime_dimension();
+///
+/// assert_eq!(format!("{force}"), "s^-2.m.kg");
+/// assert_eq!(format!("{force:#}"), "kg·m·s⁻²");
+/// assert_eq!(format!("{:#}", Voltage::runtime_dimension()), "kg·m²·s⁻³·A⁻¹");
+/// ```
+impl Display for RuntimeDimension {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() {
+            return self.fmt_conventional(f);
+        }
+
+        let mut first = true;
+        for base in SIBaseDimension::ALL {
+            let e = self.exponent(base);
+            if e == 0 {
+                continue;
+            }
+            if !first {
+                write!(f, ".")?;
+            }
+            first = false;
+            write!(f, "{}", base.symbol())?;
+            if e != 1 {
+                write!(f, "^{e}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl RuntimeDimension {
+    /// Conventional order of the base dimensions, used by the alternate [`Display`] format.
+    const CONVENTIONAL_ORDER: [SIBaseDimension; SIBaseDimension::COUNT] = [
+        SIBaseDimension::Mass,
+        SIBaseDimension::Length,
+        SIBaseDimension::Time,
+        SIBaseDimension::Current,
+        SIBaseDimension::Temperature,
+        SIBaseDimension::Substance,
+        SIBaseDimension::LightIntensity,
+        SIBaseDimension::Angle,
+        SIBaseDimension::SolidAngle,
+    ];
+
+    fn fmt_conventional(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        const SUPERSCRIPTS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+
+        let mut first = true;
+        for base in Self::CONVENTIONAL_ORDER {
+            let e = self.exponent(base);
+            if e == 0 {
+                continue;
+            }
+            if !first {
+                write!(f, "·")?;
+            }
+            first = false;
+            write!(f, "{}", base.symbol())?;
+            if e != 1 {
+                if e < 0 {
+                    write!(f, "⁻")?;
+                }
+                for digit in e.unsigned_abs().to_string().bytes() {
+                    write!(f, "{}", SUPERSCRIPTS[(digit - b'0') as usize])?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Error type for the parsing of a [`RuntimeDimension`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseRuntimeDimensionError(pub String);
+
+impl Display for ParseRuntimeDimensionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl std::error::Error for ParseRuntimeDimensionError {}
+
+/// Parses the format of the [`Display`] implementation (`s^-1.m`).
+/// A dimensionless dimension is written `1` or as an empty string.
+///
+/// ```
+/// use rust_units::si_system::{dimensions::*, runtime::*};
+///
+/// assert_eq!("s^-2.m.kg".parse(), Ok(Force::runtime_dimension()));
+/// assert_eq!("1".parse(), Ok(RuntimeDimension::DIMENSIONLESS));
+/// assert!("m^x".parse::<RuntimeDimension>().is_err());
+/// assert!("m^100.m^100".parse::<RuntimeDimension>().is_err());
+/// ```
+impl FromStr for RuntimeDimension {
+    type Err = ParseRuntimeDimensionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let mut dimension = Self::DIMENSIONLESS;
+        if s.is_empty() || s == "1" {
+            return Ok(dimension);
+        }
+
+        for factor in s.split('.') {
+            let (symbol, exponent) = match factor.split_once('^') {
+                Some((symbol, exponent)) => (
+                    symbol,
+                    exponent.parse::<i8>().map_err(|_| {
+                        ParseRuntimeDimensionError(format!("invalid exponent: '{}'", exponent))
+                    })?,
+                ),
+                None => (factor, 1),
+            };
+            let base = SIBaseDimension::from_symbol(symbol).ok_or_else(|| {
+                ParseRuntimeDimensionError(format!("unknown base dimension symbol: '{}'", symbol))
+            })?;
+            let total = &mut dimension.exponents[base.index()];
+            *total = total.checked_add(exponent).ok_or_else(|| {
+                ParseRuntimeDimensionError(format!("exponent overflow on base dimension: '{}'", symbol))
+            })?;
+        }
+        Ok(dimension)
+    }
+}
+
+/// Error returned by the operations checking dimensions at runtime.
+///
+/// ```
+/// use rust_units::si_system::{dimensions::*, runtime::*};
+///
+/// let error = DimensionError::Mismatch {
+///     expected: Speed::runtime_dimension(),
+///     found: Length::runtime_dimension(),
+/// };
+///
+/// assert_eq!(error.to_string(), "dimension mismatch: expected [s^-1.m], found [m]");
+/// assert_eq!(DimensionError::UnknownUnit("furlong".to_string()).to_string(), "unknown unit: 'furlong'");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DimensionError {
+    /// The dimension is not the expected one.
+    Mismatch {
+        /// The dimension that was expected.
+        expected: RuntimeDimension,
+        /// The dimension that was found instead.
+        found: RuntimeDimension,
+    },
+    /// The unit is not known, so its dimension can not be checked.
+    UnknownUnit(String),
+    /// The input (for example a unit expression) could not be parsed.
+    Malformed(String),
+    /// An exponent of the dimension is out of the range of [`i8`].
+    ExponentOverflow,
+}
+
+impl Display for DimensionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DimensionError::Mismatch { expected, found } => {
+                write!(f, "dimension mismatch: expected [{}], found [{}]", expected, found)
+            }
+            DimensionError::UnknownUnit(unit) => write!(f, "unknown unit: '{}'", unit),
+            DimensionError::Malformed(message) => write!(f, "malformed input: {}", message),
+            DimensionError::ExponentOverflow => write!(f, "dimension exponent overflow"),
+        }
+    }
+}
+
+impl std::error::Error for DimensionError {}
+
+/// Trait implemented by the [`SIDimension`]s that can be converted to a [`RuntimeDimension`].
+///
+/// These are all the dimensions of the [`SISystemWithAngles`](super::SISystemWithAngles).
+pub trait ToRuntimeDimension: Dimension {
+    /// The [`RuntimeDimension`] corresponding to the type.
+    const RUNTIME_DIMENSION: RuntimeDimension;
+
+    /// Returns the [`RuntimeDimension`] corresponding to the type.
+    fn runtime_dimension() -> RuntimeDimension {
+        Self::RUNTIME_DIMENSION
+    }
+}
+
+impl<D> ToRuntimeDimension for SIDimension<D>
+where
+    Self: Dimension,
+    D: RuntimeExponents,
+{
+    const RUNTIME_DIMENSION: RuntimeDimension = RuntimeDimension::from_exponents(D::EXPONENTS);
+}
+
+/// Exponents of the base dimensions of the content of a [`SIDimension`].
+///
+/// Used to implement [`ToRuntimeDimension`].
+pub trait RuntimeExponents {
+    /// The exponents, in the order of [`SIBaseDimension::ALL`].
+    const EXPONENTS: [i8; SIBaseDimension::COUNT];
+}
+
+impl RuntimeExponents for Dimensionless {
+    const EXPONENTS: [i8; SIBaseDimension::COUNT] = [0; SIBaseDimension::COUNT];
+}
+
+impl<I, O, E, Rest> RuntimeExponents for SIDim<I, O, E, Rest>
+where
+    I: TypeBaseDimension,
+    E: RuntimeExponent,
+    Rest: RuntimeExponents,
+{
+    const EXPONENTS: [i8; SIBaseDimension::COUNT] = {
+        let mut exponents = Rest::EXPONENTS;
+        exponents[I::BASE.index()] = E::EXPONENT;
+        exponents
+    };
+}
+
+/// Value of an exponent type (`E` parameter of [`SIDim`]), known at runtime.
+pub trait RuntimeExponent {
+    /// The value of the exponent.
+    const EXPONENT: i8;
+}
+
+impl<E: Integer> RuntimeExponent for SIExponent<CrossInt<E>> {
+    const EXPONENT: i8 = E::I8;
+}
+
+/// A unit proportional to the work unit, whose dimension is only known at runtime.
+///
+/// If k is the proportionality constant, then [`WorkUnit`](crate::WorkUnit) = k * ThisUnit.
+///
+/// Since the dimension is not known at compile time, it does not implement [`Unit`].
+/// Instead, all the conversions check the dimension at runtime and return a [`Result`].
+///
+/// ```
+/// use rust_units::Unit;
+/// use rust_units::si_system::{dimensions::*, runtime::*, units::*};
+///
+/// let registry = [
+///     ("km", DynUnit::from_unit(&METER.set_kilo_prefix())),
+///     ("s", DynUnit::from_unit(&SECOND)),
+/// ];
+///
+/// let distance = METER.build(1500.0);
+///
+/// assert_eq!(distance.try_get_in(&registry[0].1), Ok(1.5));
+/// assert_eq!(
+///     distance.try_get_in(&registry[1].1),
+///     Err(DimensionError::Mismatch {
+///         expected: Length::runtime_dimension(),
+///         found: Time::runtime_dimension(),
+///     })
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DynUnit {
+    dimension: RuntimeDimension,
+    prop_constant: f64,
+}
+
+impl DynUnit {
+    /// Creates a new [`DynUnit`] from its dimension and its proportionality constant.
+    ///
+    /// The proportionality constant must be non zero, as the unit would then be meaningless.
+    pub const fn new(dimension: RuntimeDimension, prop_constant: f64) -> Self {
+        Self {
+            dimension,
+            prop_constant,
+        }
+    }
+
+    /// Creates a new [`DynUnit`] from a unit known at compile time, moving its dimension from the type to a value.
+    ///
+    /// The proportionality constant is the one of [`WorkPropUnit::from_unit`], with the same requirements on `unit`.
+    pub fn from_unit<U>(unit: &U) -> Self
+    where
+        U: Unit<f64>,
+        U::Dimension: ToRuntimeDimension,
+    {
+        Self::new(
+            U::Dimension::runtime_dimension(),
+            WorkPropUnit::from_unit(unit).work_per_unit(),
+        )
+    }
+
+    /// Returns the dimension of the unit.
+    pub const fn dimension(&self) -> RuntimeDimension {
+        self.dimension
+    }
+
+    /// Returns the proportionality constant of the unit.
+    pub const fn prop_constant(&self) -> f64 {
+        self.prop_constant
+    }
+
+    /// Converts a value into a [`Quantity`], if the dimension of the unit is `D`.
+    pub fn try_build<T, D: ToRuntimeDimension>(&self, value: T) -> Result<Quantity<T, D>, DimensionError>
+    where
+        T: Mul<f64, Output = T>,
+    {
+        self.dimension.check(D::runtime_dimension())?;
+        Ok(Quantity::from_work(value * self.prop_constant))
+    }
+
+    /// Retrieves the value of a [`Quantity`], if the dimension of the unit is `D`.
+    pub fn try_get<T, D: ToRuntimeDimension>(&self, quantity: Quantity<T, D>) -> Result<T, DimensionError>
+    where
+        T: Div<f64, Output = T>,
+    {
+        self.dimension.check(D::runtime_dimension())?;
+        Ok(quantity.get_work() / self.prop_constant)
+    }
+
+    /// Converts a value into a [`DynQuantity`]. This can not fail.
+    pub fn build_dyn<T>(&self, value: T) -> DynQuantity<T>
+    where
+        T: Mul<f64, Output = T>,
+    {
+        DynQuantity {
+            value: value * self.prop_constant,
+            dimension: self.dimension,
+        }
+    }
+
+    /// Retrieves the value of a [`DynQuantity`], if it has the same dimension as the unit.
+    ///
+    /// ```
+    /// use rust_units::si_system::{runtime::*, units::*};
+    ///
+    /// let hour = DynUnit::from_unit(&SECOND).build_dyn(3600.0);
+    ///
+    /// assert_eq!(DynUnit::from_unit(&SECOND.set_kilo_prefix()).get_dyn(hour), Ok(3.6));
+    /// assert!(DynUnit::from_unit(&METER).get_dyn(hour).is_err());
+    /// ```
+    pub fn get_dyn<T>(&self, quantity: DynQuantity<T>) -> Result<T, DimensionError>
+    where
+        T: Div<f64, Output = T>,
+    {
+        self.dimension.check(quantity.dimension)?;
+        Ok(quantity.value / self.prop_constant)
+    }
+}
+
+impl DynUnit {
+    /// Raises the unit to an integer power.
+    ///
+    /// **Panics** if an exponent of the dimension overflows, see [`checked_powi`](DynUnit::checked_powi).
+    pub fn powi(self, n: i8) -> Self {
+        self.checked_powi(n).expect("dimension exponent overflow")
+    }
+
+    /// Multiplies two units, or returns `None` if an exponent of the dimension overflows.
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        Some(Self::new(
+            self.dimension.checked_mul(rhs.dimension)?,
+            self.prop_constant * rhs.prop_constant,
+        ))
+    }
+
+    /// Divides two units, or returns `None` if an exponent of the dimension overflows.
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        Some(Self::new(
+            self.dimension.checked_div(rhs.dimension)?,
+            self.prop_constant / rhs.prop_constant,
+        ))
+    }
+
+    /// Raises the unit to an integer power, or returns `None` if an exponent of the dimension overflows.
+    pub fn checked_powi(self, n: i8) -> Option<Self> {
+        Some(Self::new(self.dimension.checked_powi(n)?, self.prop_constant.powi(n.into())))
+    }
+}
+
+impl Mul for DynUnit {
+    type Output = Self;
+
+    /// Multiplies two units.
+    ///
+    /// **Panics** if an exponent of the dimension overflows, see [`checked_mul`](DynUnit::checked_mul).
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.checked_mul(rhs).expect("dimension exponent overflow")
+    }
+}
+
+impl Div for DynUnit {
+    type Output = Self;
+
+    /// Divides two units.
+    ///
+    /// **Panics** if an exponent of the dimension overflows, see [`checked_div`](DynUnit::checked_div).
+    fn div(self, rhs: Self) -> Self::Output {
+        self.checked_div(rhs).expect("dimension exponent overflow")
+    }
+}
+
+/// A dimensioned value, whose dimension is only known at runtime.
+///
+/// It is the runtime counterpart of [`Quantity`]. The value is stored in work unit.
+///
+/// ```
+/// use rust_units::{Quantity, Dimension};
+/// use rust_units::si_system::{dimensions::*, runtime::*};
+///
+/// let dyn_speed = DynQuantity::from(Speed::from_work(3.0));
+///
+/// let speed: Result<Quantity<f64, Speed>, _> = dyn_speed.try_into_quantity();
+/// let length: Result<Quantity<f64, Length>, _> = dyn_speed.try_into_quantity();
+///
+/// assert_eq!(speed, Ok(Speed::from_work(3.0)));
+/// assert!(length.is_err());
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DynQuantity<T> {
+    value: T,
+    dimension: RuntimeDimension,
+}
+
+impl<T> DynQuantity<T> {
+    /// Creates a new quantity from its value in work unit and its dimension.
+    ///
+    /// ```
+    /// use rust_units::si_system::{dimensions::*, runtime::*};
+    ///
+    /// let dimension = "s^-2.m".parse().unwrap();
+    /// let acceleration = DynQuantity::new(9.81, dimension);
+    ///
+    /// assert_eq!(*acceleration.value(), 9.81);
+    /// assert_eq!(acceleration.dimension(), Acceleration::runtime_dimension());
+    /// assert_eq!(acceleration.try_into_quantity::<Acceleration>().unwrap().get_work(), 9.81);
+    /// ```
+    pub const fn new(value: T, dimension: RuntimeDimension) -> Self {
+        Self { value, dimension }
+    }
+
+    /// Returns a reference to the value, in work unit.
+    pub const fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns the dimension of the quantity.
+    pub const fn dimension(&self) -> RuntimeDimension {
+        self.dimension
+    }
+
+    /// Converts this quantity into a [`Quantity`], if its dimension is `D`.
+    pub fn try_into_quantity<D: ToRuntimeDimension>(self) -> Result<Quantity<T, D>, DimensionError> {
+        self.dimension.check(D::runtime_dimension())?;
+        Ok(Quantity::from_work(self.value))
+    }
+}
+
+impl<T, D: ToRuntimeDimension> From<Quantity<T, D>> for DynQuantity<T> {
+    fn from(quantity: Quantity<T, D>) -> Self {
+        Self {
+            value: quantity.get_work(),
+            dimension: D::runtime_dimension(),
+        }
+    }
+}
+
+/// Multiplication of two [`DynQuantity`]s.
+///
+/// The exponents of the dimensions are added with checked arithmetic,
+/// so the result is an error if one of them overflows.
+///
+/// ```
+/// use rust_units::si_system::{dimensions::*, runtime::*};
+///
+/// let big = DynQuantity::new(2.0, Length::runtime_dimension().powi(50));
+///
+/// let mut product = big;
+/// let mut steps = 0;
+/// let error = loop {
+///     match product * big {
+///         Ok(p) => product = p,
+///         Err(e) => break e,
+///     }
+///     steps += 1;
+/// };
+///
+/// assert_eq!(steps, 1); // m^100 is fine, m^150 is not.
+/// assert_eq!(*product.value(), 4.0);
+/// assert_eq!(error, DimensionError::ExponentOverflow);
+/// assert!((big / big).unwrap().dimension().is_dimensionless());
+/// ```
+impl<Tl: Mul<Tr>, Tr> Mul<DynQuantity<Tr>> for DynQuantity<Tl> {
+    type Output = Result<DynQuantity<Tl::Output>, DimensionError>;
+
+    fn mul(self, rhs: DynQuantity<Tr>) -> Self::Output {
+        let dimension = self
+            .dimension
+            .checked_mul(rhs.dimension)
+            .ok_or(DimensionError::ExponentOverflow)?;
+        Ok(DynQuantity::new(self.value * rhs.value, dimension))
+    }
+}
+
+/// Division of two [`DynQuantity`]s, with checked exponent arithmetic as in the [`Mul`] implementation.
+impl<Tl: Div<Tr>, Tr> Div<DynQuantity<Tr>> for DynQuantity<Tl> {
+    type Output = Result<DynQuantity<Tl::Output>, DimensionError>;
+
+    fn div(self, rhs: DynQuantity<Tr>) -> Self::Output {
+        let dimension = self
+            .dimension
+            .checked_div(rhs.dimension)
+            .ok_or(DimensionError::ExponentOverflow)?;
+        Ok(DynQuantity::new(self.value / rhs.value, dimension))
+    }
+}
+
+impl<T, D: ToRuntimeDimension> Quantity<T, D> {
+    /// Compute and returns the value in the given [`DynUnit`], if the unit has the dimension `D`.
+    pub fn try_get_in(self, unit: &DynUnit) -> Result<T, DimensionError>
+    where
+        T: Div<f64, Output = T>,
+    {
+        unit.try_get(self)
+    }
+
+    /// Returns the dimension of the quantity, known at runtime.
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Dimension};
+    /// use rust_units::si_system::{dimensions::*, runtime::*};
+    ///
+    /// let weight = Force::from_work(1.0);
+    ///
+    /// assert_eq!(weight.runtime_dimension(), RuntimeDimension::from_exponents([-2, 1, 1, 0, 0, 0, 0, 0, 0]));
+    /// assert_eq!(format!("{:#}", weight.runtime_dimension()), "kg·m·s⁻²");
+    /// ```
+    pub fn runtime_dimension(&self) -> RuntimeDimension {
+        D::runtime_dimension()
+    }
+
+    /// Returns an adapter displaying the quantity in work unit, with the conventional unit symbol
+    /// of its dimension (see the alternate format of [`RuntimeDimension`]).
+    ///
+    /// The [`Display`](std::fmt::Display) implementation of [`Quantity`] uses the symbols of the type-level dimension,
+    /// which are not ordered in the conventional way. The precision and width of the formatter apply to the value.
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Dimension};
+    /// use rust_units::si_system::dimensions::*;
+    ///
+    /// let weight = Force::from_work(10.0);
+    ///
+    /// assert_eq!(format!("{:.2}", weight.display_conventional()), "10.00 kg·m·s⁻²");
+    /// assert_eq!(format!("{:>6.1}", Acceleration::from_work(9.81).display_conventional()), "   9.8 m·s⁻²");
+    /// ```
+    pub fn display_conventional(&self) -> DisplayConventional<'_, T, D> {
+        DisplayConventional { quantity: self }
+    }
+
+    /// Checks that the dimension of the quantity is the `expected` one.
+    ///
+    /// Useful in generic code, where `D` is a type parameter.
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Dimension};
+    /// use rust_units::si_system::{dimensions::*, runtime::*};
+    ///
+    /// fn total_energy<D: ToRuntimeDimension>(q: Quantity<f64, D>) -> Result<f64, DimensionError> {
+    ///     q.check_runtime_dimension(Energy::runtime_dimension())?;
+    ///     Ok(q.get_work())
+    /// }
+    ///
+    /// assert_eq!(total_energy(Energy::from_work(2.0)), Ok(2.0));
+    /// assert!(total_energy(Power::from_work(2.0)).is_err());
+    /// ```
+    pub fn check_runtime_dimension(&self, expected: RuntimeDimension) -> Result<(), DimensionError> {
+        D::runtime_dimension().check(expected)
+    }
+
+    /// Same as [`check_runtime_dimension`](Quantity::check_runtime_dimension), but panics on mismatch.
+    ///
+    /// **Panics** if the dimension of the quantity is not the `expected` one.
+    ///
+    /// ```should_panic
+    /// use rust_units::{Quantity, Dimension};
+    /// use rust_units::si_system::{dimensions::*, runtime::*};
+    ///
+    /// Length::from_work(1.0).assert_runtime_dimension(Length::runtime_dimension()); // Ok
+    /// Length::from_work(1.0).assert_runtime_dimension(Time::runtime_dimension()); // Panics
+    /// ```
+    pub fn assert_runtime_dimension(&self, expected: RuntimeDimension) {
+        if let Err(error) = self.check_runtime_dimension(expected) {
+            panic!("{error}")
+        }
+    }
+
+    /// Converts the quantity to another representation `D2` of the same dimension.
+    ///
+    /// The dimensions are compared with their [`RuntimeDimension`], so this bridges any two dimension types
+    /// implementing [`ToRuntimeDimension`], for example the type-level [`SIDimension`]s and a user-defined
+    /// representation based on const generics. The value is unchanged, as both use the same work unit.
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Dimension};
+    /// use rust_units::si_system::{dimensions::*, runtime::*};
+    ///
+    /// // A dimension whose exponents are const generics, in the order of SIBaseDimension::ALL.
+    /// struct ConstDim<const S: i8, const M: i8>;
+    /// impl<const S: i8, const M: i8> Dimension for ConstDim<S, M> {}
+    /// impl<const S: i8, const M: i8> ToRuntimeDimension for ConstDim<S, M> {
+    ///     const RUNTIME_DIMENSION: RuntimeDimension = RuntimeDimension::from_exponents([S, M, 0, 0, 0, 0, 0, 0, 0]);
+    /// }
+    ///
+    /// let velocity = Velocity::from_work(3.0);
+    ///
+    /// let converted: Quantity<f64, ConstDim<-1, 1>> = velocity.try_cast_dimension().unwrap();
+    /// assert_eq!(converted.get_work(), 3.0);
+    /// assert_eq!(converted.try_cast_dimension::<Velocity>(), Ok(velocity));
+    ///
+    /// assert!(velocity.try_cast_dimension::<ConstDim<-2, 1>>().is_err());
+    /// ```
+    pub fn try_cast_dimension<D2: ToRuntimeDimension>(self) -> Result<Quantity<T, D2>, DimensionError> {
+        D::runtime_dimension().check(D2::runtime_dimension())?;
+        Ok(Quantity::from_work(self.get_work()))
+    }
+}
+
+impl<D: ToRuntimeDimension> Quantity<f64, D> {
+    /// Returns the value in work unit in scientific notation, `(mantissa, exponent)` with `value = mantissa * 10^exponent`,
+    /// along with the exponents of the dimension (see [`RuntimeDimension::exponents`]). Meant for compact logs.
+    ///
+    /// The absolute value of the mantissa is in `[1, 10[`, except for zero and non finite values,
+    /// which are returned as is with an exponent of zero.
+    ///
+    /// See [`from_sci_tuple`](Quantity::from_sci_tuple) for the reverse conversion.
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Unit};
+    /// use rust_units::si_system::{dimensions::Force, units::*};
+    ///
+    /// let (mantissa, exponent, dimension) = NEWTON.build(-1520.0).to_sci_tuple();
+    ///
+    /// assert!((mantissa + 1.52).abs() < 1e-12);
+    /// assert_eq!(exponent, 3);
+    /// assert_eq!(dimension, [-2, 1, 1, 0, 0, 0, 0, 0, 0]);
+    ///
+    /// for value in [6.02e23, 9.81, 1.0, 0.5, -3.3e-7, 1.6e-19, 0.0] {
+    ///     let force = NEWTON.build(value);
+    ///     let back = Quantity::<f64, Force>::from_sci_tuple(force.to_sci_tuple()).unwrap();
+    ///     assert!((back.get_work() - value).abs() <= value.abs() * 1e-14);
+    /// }
+    /// ```
+    pub fn to_sci_tuple(self) -> (f64, i32, [i8; SIBaseDimension::COUNT]) {
+        let value = self.get_work();
+        let dimension = D::runtime_dimension().exponents();
+        if value == 0.0 || !value.is_finite() {
+            return (value, 0, dimension);
+        }
+
+        let mut exponent = value.abs().log10().floor() as i32;
+        let mut mantissa = value / 10f64.powi(exponent);
+        // Corrects the rounding errors of the logarithm.
+        if mantissa.abs() >= 10.0 {
+            mantissa /= 10.0;
+            exponent += 1;
+        } else if mantissa.abs() < 1.0 {
+            mantissa *= 10.0;
+            exponent -= 1;
+        }
+        (mantissa, exponent, dimension)
+    }
+
+    /// Builds a quantity from the output of [`to_sci_tuple`](Quantity::to_sci_tuple),
+    /// if the exponents of the dimension are the ones of `D`.
+    pub fn from_sci_tuple(
+        (mantissa, exponent, dimension): (f64, i32, [i8; SIBaseDimension::COUNT]),
+    ) -> Result<Self, DimensionError> {
+        RuntimeDimension::from_exponents(dimension).check(D::runtime_dimension())?;
+        Ok(Quantity::from_work(mantissa * 10f64.powi(exponent)))
+    }
+}
+
+/// Formats a slice of quantities compactly, with the values followed by a single unit symbol.
+///
+/// The returned adapter implements [`Debug`], and is meant for logging.
+///
+/// ```
+/// use rust_units::{Quantity, Unit};
+/// use rust_units::si_system::{runtime::debug_compact, units::*};
+///
+/// let lengths = [3.0, 4.0, 5.0].map(|x| METER.build(x));
+///
+/// assert_eq!(format!("{:?}", debug_compact(&lengths)), "[3.0, 4.0, 5.0] m");
+/// ```
+pub fn debug_compact<T: Debug, D: ToRuntimeDimension>(slice: &[Quantity<T, D>]) -> DebugCompact<'_, T, D> {
+    DebugCompact { slice }
+}
+
+/// Adapter returned by [`debug_compact`].
+pub struct DebugCompact<'a, T, D: Dimension> {
+    slice: &'a [Quantity<T, D>],
+}
+
+impl<T: Debug, D: ToRuntimeDimension> Debug for DebugCompact<'_, T, D> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list()
+            .entries(self.slice.iter().map(Quantity::get_ref_work))
+            .finish()?;
+        let dimension = D::runtime_dimension();
+        if !dimension.is_dimensionless() {
+            write!(f, " {dimension}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Adapter returned by [`Quantity::display_conventional`].
+pub struct DisplayConventional<'a, T, D: Dimension> {
+    quantity: &'a Quantity<T, D>,
+}
+
+impl<T: Display, D: ToRuntimeDimension> Display for DisplayConventional<'_, T, D> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self.quantity.get_ref_work(), f)?;
+        let dimension = D::runtime_dimension();
+        if !dimension.is_dimensionless() {
+            write!(f, " {dimension:#}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A table of [`DynUnit`]s indexed by their symbol, to convert values between units only known at runtime.
+///
+/// It can be parsed from a text file with one unit per line, written as `symbol factor dimension`,
+/// where `factor` is the finite and non zero proportionality constant of the unit (one unit is worth `factor` work units)
+/// and `dimension` uses the format of [`RuntimeDimension`]. Empty lines and lines starting with `#` are ignored.
+///
+/// ```
+/// use rust_units::si_system::runtime::*;
+///
+/// // Usually loaded with std::fs::read_to_string.
+/// let file = "
+///     # symbol factor dimension
+///     km    1000  m
+///     mi    1609.344  m
+///     h     3600  s
+/// ";
+///
+/// let table: ConversionTable = file.parse().unwrap();
+///
+/// assert_eq!(table.convert(2.0, "km", "m"), Err(DimensionError::UnknownUnit("m".to_string())));
+/// assert_eq!(table.convert(1.609344, "km", "mi"), Ok(1.0));
+/// assert!(matches!(
+///     table.convert(1.0, "km", "h"),
+///     Err(DimensionError::Mismatch { .. })
+/// ));
+///
+/// // Compound units are built from the units of the table.
+/// assert!((table.convert(1.0, "mi/h", "km/h").unwrap() - 1.609344).abs() < 1e-12);
+///
+/// // The factors must be finite and non zero.
+/// let error = "km 1000 m\nzero 0 m".parse::<ConversionTable>().unwrap_err();
+/// assert_eq!(error.to_string(), "line 2: invalid factor: '0'");
+/// assert!("nan NaN m".parse::<ConversionTable>().is_err());
+/// assert!("inf inf m".parse::<ConversionTable>().is_err());
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ConversionTable {
+    units: HashMap<String, DynUnit>,
+}
+
+impl ConversionTable {
+    /// Creates an empty table.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a unit to the table. If a unit already had this symbol, it is replaced and returned.
+    pub fn insert(&mut self, symbol: impl Into<String>, unit: DynUnit) -> Option<DynUnit> {
+        self.units.insert(symbol.into(), unit)
+    }
+
+    /// Returns the unit with the given symbol, if any.
+    pub fn get(&self, symbol: &str) -> Option<&DynUnit> {
+        self.units.get(symbol)
+    }
+
+    /// Parses a unit expression, made of the symbols of the table combined with `*`, `/`, `^` and parentheses.
+    ///
+    /// `*` and `/` have the same precedence and are left associative, `^` takes an integer exponent
+    /// and binds tighter. `1` can be used as a dimensionless unit (as in `1/s`).
+    /// A symbol of the table is always used as is, even if it contains these operators.
+    ///
+    /// ```
+    /// use rust_units::si_system::{dimensions::*, runtime::*, units::*};
+    ///
+    /// let mut table = ConversionTable::new();
+    /// table.insert("kg", DynUnit::from_unit(&KILOGRAM));
+    /// table.insert("m", DynUnit::from_unit(&METER));
+    /// table.insert("s", DynUnit::from_unit(&SECOND));
+    ///
+    /// let newton = table.parse_unit("kg*m/s^2").unwrap();
+    /// let pascal = table.parse_unit("kg/(m*s^2)").unwrap();
+    ///
+    /// assert_eq!(newton.dimension(), Force::runtime_dimension());
+    /// assert_eq!(pascal.dimension(), Pressure::runtime_dimension());
+    /// assert_eq!(table.parse_unit("(m/s)^2").unwrap().dimension(), table.parse_unit("m^2/s^2").unwrap().dimension());
+    /// assert_eq!(table.parse_unit("1/s").unwrap().dimension(), Frequency::runtime_dimension());
+    ///
+    /// for malformed in ["kg*/m", "(m", "m)", "m^x", "", "m s"] {
+    ///     assert!(matches!(table.parse_unit(malformed), Err(DimensionError::Malformed(_))));
+    /// }
+    /// assert_eq!(table.parse_unit("kg*ft"), Err(DimensionError::UnknownUnit("ft".to_string())));
+    ///
+    /// // The exponents of the dimensions are `i8`s.
+    /// for overflowing in ["m^100*m^100", "(m^-128)^-1", "m^127*m^127*m^2", "1/m^127/m^2"] {
+    ///     assert_eq!(table.parse_unit(overflowing), Err(DimensionError::ExponentOverflow));
+    /// }
+    /// ```
+    pub fn parse_unit(&self, expression: &str) -> Result<DynUnit, DimensionError> {
+        if let Some(unit) = self.get(expression.trim()) {
+            return Ok(*unit);
+        }
+        UnitExpressionParser::new(self, expression).parse()
+    }
+
+    /// Parses a quantity written as a number followed by a unit expression (see [`parse_unit`](ConversionTable::parse_unit)),
+    /// such as `9.81 kg*m/s^2`, and checks that its dimension is `D`.
+    ///
+    /// ```
+    /// use rust_units::{Quantity, Dimension};
+    /// use rust_units::si_system::{dimensions::*, runtime::*};
+    ///
+    /// let table: ConversionTable = "kg 1 kg \n m 1 m \n s 1 s".parse().unwrap();
+    ///
+    /// let force: Quantity<f64, Force> = table.parse_quantity("9.5 kg*m/s^2").unwrap();
+    ///
+    /// assert_eq!(force, Force::from_work(9.5));
+    /// assert!(table.parse_quantity::<Pressure>("9.5 kg*m/s^2").is_err());
+    /// assert!(table.parse_quantity::<Force>("nine kg*m/s^2").is_err());
+    /// ```
+    pub fn parse_quantity<D: ToRuntimeDimension>(&self, s: &str) -> Result<Quantity<f64, D>, DimensionError> {
+        let s = s.trim();
+        let (value, unit) = s.split_once(char::is_whitespace).unwrap_or((s, "1"));
+        let value = value
+            .parse::<f64>()
+            .map_err(|_| DimensionError::Malformed(format!("invalid number: '{}'", value)))?;
+        self.parse_unit(unit)?.try_build(value)
+    }
+
+    /// Converts a value from the unit `from` to the unit `to`.
+    ///
+    /// The units can be unit expressions (see [`parse_unit`](ConversionTable::parse_unit)).
+    ///
+    /// Fails if one of the units can not be parsed, or if they do not have the same dimension.
+    pub fn convert(&self, value: f64, from: &str, to: &str) -> Result<f64, DimensionError> {
+        let (from, to) = (self.parse_unit(from)?, self.parse_unit(to)?);
+        to.get_dyn(from.build_dyn(value))
+    }
+}
+
+/// Recursive descent parser of the unit expressions of [`ConversionTable::parse_unit`].
+struct UnitExpressionParser<'a> {
+    table: &'a ConversionTable,
+    input: &'a str,
+    rest: &'a str,
+}
+
+impl<'a> UnitExpressionParser<'a> {
+    fn new(table: &'a ConversionTable, input: &'a str) -> Self {
+        Self {
+            table,
+            input,
+            rest: input,
+        }
+    }
+
+    fn error(&self, message: &str) -> DimensionError {
+        DimensionError::Malformed(format!("{} in unit '{}'", message, self.input))
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.rest = self.rest.trim_start();
+        self.rest.chars().next()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.rest = &self.rest[c.len_utf8()..];
+            true
+        } else {
+            false
+        }
+    }
+
+    fn parse(mut self) -> Result<DynUnit, DimensionError> {
+        let unit = self.expression()?;
+        match self.peek() {
+            None => Ok(unit),
+            Some(c) => Err(self.error(&format!("unexpected '{}'", c))),
+        }
+    }
+
+    // expression = power (('*' | '/') power)*
+    fn expression(&mut self) -> Result<DynUnit, DimensionError> {
+        let mut unit = self.power()?;
+        loop {
+            let combined = if self.eat('*') {
+                unit.checked_mul(self.power()?)
+            } else if self.eat('/') {
+                unit.checked_div(self.power()?)
+            } else {
+                return Ok(unit);
+            };
+            unit = combined.ok_or(DimensionError::ExponentOverflow)?;
+        }
+    }
+
+    // power = atom ('^' integer)?
+    fn power(&mut self) -> Result<DynUnit, DimensionError> {
+        let unit = self.atom()?;
+        if !self.eat('^') {
+            return Ok(unit);
+        }
+        let parenthesized = self.eat('(');
+        self.peek();
+        let end = self
+            .rest
+            .char_indices()
+            .find(|&(i, c)| !(c.is_ascii_digit() || (i == 0 && (c == '-' || c == '+'))))
+            .map_or(self.rest.len(), |(i, _)| i);
+        let exponent = self.rest[..end]
+            .parse::<i8>()
+            .map_err(|_| self.error("invalid exponent"))?;
+        self.rest = &self.rest[end..];
+        if parenthesized && !self.eat(')') {
+            return Err(self.error("missing ')'"));
+        }
+        unit.checked_powi(exponent).ok_or(DimensionError::ExponentOverflow)
+    }
+
+    // atom = symbol | '1' | '(' expression ')'
+    fn atom(&mut self) -> Result<DynUnit, DimensionError> {
+        if self.eat('(') {
+            let unit = self.expression()?;
+            if !self.eat(')') {
+                return Err(self.error("missing ')'"));
+            }
+            return Ok(unit);
+        }
+
+        self.peek();
+        let end = self
+            .rest
+            .find(|c: char| c.is_whitespace() || "*/^()".contains(c))
+            .unwrap_or(self.rest.len());
+        let symbol = &self.rest[..end];
+        self.rest = &self.rest[end..];
+        match symbol {
+            "" => Err(self.error("missing unit")),
+            "1" => Ok(DynUnit::new(RuntimeDimension::DIMENSIONLESS, 1.0)),
+            symbol => self
+                .table
+                .get(symbol)
+                .copied()
+                .ok_or_else(|| DimensionError::UnknownUnit(symbol.to_string())),
+        }
+    }
+}
+
+impl FromStr for ConversionTable {
+    type Err = ParseConversionTableError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut table = Self::new();
+        for (number, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let error = |message: String| ParseConversionTableError(format!("line {}: {}", number + 1, message));
+
+            let mut fields = line.split_whitespace();
+            let (Some(symbol), Some(factor), Some(dimension), None) =
+                (fields.next(), fields.next(), fields.next(), fields.next())
+            else {
+                return Err(error("expected 'symbol factor dimension'".to_string()));
+            };
+            let factor = factor
+                .parse::<f64>()
+                .ok()
+                .filter(|value| value.is_finite() && *value != 0.0)
+                .ok_or_else(|| error(format!("invalid factor: '{}'", factor)))?;
+            let dimension = dimension.parse().map_err(|e: ParseRuntimeDimensionError| error(e.0))?;
+
+            table.insert(symbol, DynUnit::new(dimension, factor));
+        }
+        Ok(table)
+    }
+}
+
+/// Error type for the parsing of a [`ConversionTable`].
+#[derive(Debug, Clone)]
+pub struct ParseConversionTableError(pub String);
+
+impl Display for ParseConversionTableError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl std::error::Error for ParseConversionTableError {}
+
+/// Checks the dimensional consistency of an equation at runtime, by following its operations.
+///
+/// The operations are applied from left to right, each on a named term. The first inconsistent addition
+/// (or subtraction), or the first operation overflowing an exponent, is recorded along with the expression
+/// built so far, and the following operations are ignored.
+///
+/// ```
+/// use rust_units::si_system::{dimensions::*, runtime::*};
+///
+/// // F = m * a
+/// let force = DimensionCheck::new("m", Mass::runtime_dimension())
+///     .times("a", Acceleration::runtime_dimension());
+/// assert_eq!(force.check(Force::runtime_dimension()), Ok(Force::runtime_dimension()));
+///
+/// // E = m * v^2 + m * g: the second term is a force, not an energy.
+/// let energy = DimensionCheck::new("m", Mass::runtime_dimension())
+///     .times("v^2", Speed::runtime_dimension().powi(2))
+///     .plus("m * g", Force::runtime_dimension())
+///     .per("2", RuntimeDimension::DIMENSIONLESS);
+///
+/// let error = energy.check(Energy::runtime_dimension()).unwrap_err();
+/// assert_eq!(error.expression, "m * v^2 + m * g");
+/// assert_eq!(
+///     error.error,
+///     DimensionError::Mismatch { expected: Energy::runtime_dimension(), found: Force::runtime_dimension() }
+/// );
+///
+/// // The final dimension is also checked.
+/// assert!(force.check(Energy::runtime_dimension()).is_err());
+///
+/// // The exponents of the dimensions are `i8`s.
+/// let x = Length::runtime_dimension();
+/// let huge = DimensionCheck::new("x^100", x.powi(100)).times("x^100", x.powi(100));
+/// let error = huge.check(x).unwrap_err();
+/// assert_eq!(error.expression, "x^100 * x^100");
+/// assert_eq!(error.error, DimensionError::ExponentOverflow);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct DimensionCheck {
+    expression: String,
+    dimension: RuntimeDimension,
+    error: Option<DimensionCheckError>,
+}
+
+impl DimensionCheck {
+    /// Starts a check with the first term of the equation.
+    pub fn new(name: impl Into<String>, dimension: RuntimeDimension) -> Self {
+        Self {
+            expression: name.into(),
+            dimension,
+            error: None,
+        }
+    }
+
+    /// Returns the dimension of the expression built so far.
+    pub fn dimension(&self) -> RuntimeDimension {
+        self.dimension
+    }
+
+    /// Multiplies the expression by a term.
+    pub fn times(self, name: &str, dimension: RuntimeDimension) -> Self {
+        self.apply("*", name, |d| d.checked_mul(dimension).ok_or(DimensionError::ExponentOverflow))
+    }
+
+    /// Divides the expression by a term.
+    pub fn per(self, name: &str, dimension: RuntimeDimension) -> Self {
+        self.apply("/", name, |d| d.checked_div(dimension).ok_or(DimensionError::ExponentOverflow))
+    }
+
+    /// Adds a term to the expression, which must have the same dimension.
+    pub fn plus(self, name: &str, dimension: RuntimeDimension) -> Self {
+        self.apply("+", name, |d| dimension.check(d).map(|_| d))
+    }
+
+    /// Subtracts a term from the expression, which must have the same dimension.
+    pub fn minus(self, name: &str, dimension: RuntimeDimension) -> Self {
+        self.apply("-", name, |d| dimension.check(d).map(|_| d))
+    }
+
+    /// Raises the expression to an integer power.
+    pub fn powi(self, n: i8) -> Self {
+        let name = n.to_string();
+        self.apply("^", &name, |d| d.checked_powi(n).ok_or(DimensionError::ExponentOverflow))
+    }
+
+    /// Checks that no operation was inconsistent, and that the final dimension is the `expected` one.
+    ///
+    /// Returns the final dimension on success.
+    pub fn check(&self, expected: RuntimeDimension) -> Result<RuntimeDimension, DimensionCheckError> {
+        if let Some(error) = &self.error {
+            return Err(error.clone());
+        }
+        self.dimension.check(expected).map_err(|error| DimensionCheckError {
+            expression: self.expression.clone(),
+            error,
+        })?;
+        Ok(self.dimension)
+    }
+
+    fn apply<F>(mut self, operator: &str, name: &str, operation: F) -> Self
+    where
+        F: FnOnce(RuntimeDimension) -> Result<RuntimeDimension, DimensionError>,
+    {
+        if self.error.is_some() {
+            return self;
+        }
+        let expression = if operator == "^" {
+            format!("({})^{}", self.expression, name)
+        } else {
+            format!("{} {} {}", self.expression, operator, name)
+        };
+        match operation(self.dimension) {
+            Ok(dimension) => self.dimension = dimension,
+            Err(error) => self.error = Some(DimensionCheckError { expression: expression.clone(), error }),
+        }
+        self.expression = expression;
+        self
+    }
+}
+
+/// Error returned by [`DimensionCheck::check`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DimensionCheckError {
+    /// The expression, up to the first inconsistent operation.
+    pub expression: String,
+    /// The inconsistency.
+    pub error: DimensionError,
+}
+
+impl Display for DimensionCheckError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "in '{}': {}", self.expression, self.error)
+    }
+}
+
+impl std::error::Error for DimensionCheckError {}
+
+/// Types whose dimension is known at runtime, such as a [`Quantity`] or a [`DynQuantity`].
+///
+/// Used by [`CheckDimensions`] to check the fields of a struct.
+pub trait RuntimeDimensionOf {
+    /// Returns the dimension of the value.
+    fn runtime_dimension_of(&self) -> RuntimeDimension;
+}
+
+impl<T, D: ToRuntimeDimension> RuntimeDimensionOf for Quantity<T, D> {
+    fn runtime_dimension_of(&self) -> RuntimeDimension {
+        D::runtime_dimension()
+    }
+}
+
+impl<T> RuntimeDimensionOf for DynQuantity<T> {
+    fn runtime_dimension_of(&self) -> RuntimeDimension {
+        self.dimension
+    }
+}
+
+/// Checks the dimensions of the fields of a struct against their expected dimensions.
+///
+/// It is meant to be derived, with the `derive` feature: the expected dimension of each checked field
+/// is given by a `#[dimension(...)]` attribute, holding a type implementing [`ToRuntimeDimension`].
+/// The type of the field must implement [`RuntimeDimensionOf`]. Fields without the attribute are not checked.
+///
+#[cfg_attr(feature = "derive", doc = "```")]
+#[cfg_attr(not(feature = "derive"), doc = "```ignore")]
+/// use rust_units::Unit;
+/// use rust_units::si_system::{dimensions::*, runtime::*, units::*};
+///
+/// #[derive(CheckDimensions)]
+/// struct Pendulum {
+///     #[dimension(Length)]
+///     length: DynQuantity<f64>,
+///     #[dimension(Mass)]
+///     mass: DynQuantity<f64>,
+///     #[dimension(Energy)]
+///     weight: DynQuantity<f64>,
+///     name: &'static str,
+/// }
+///
+/// let pendulum = Pendulum {
+///     length: METER.build(1.0).into(),
+///     mass: KILOGRAM.build(0.5).into(),
+///     weight: NEWTON.build(4.9).into(),
+///     name: "Foucault",
+/// };
+///
+/// let errors = pendulum.check_dimensions().unwrap_err();
+/// assert_eq!(
+///     errors,
+///     [FieldDimensionError {
+///         field: "weight",
+///         error: DimensionError::Mismatch {
+///             expected: Energy::runtime_dimension(),
+///             found: Force::runtime_dimension(),
+///         },
+///     }]
+/// );
+/// assert_eq!(errors[0].to_string(), "field 'weight': ".to_string() + &errors[0].error.to_string());
+/// ```
+pub trait CheckDimensions {
+    /// Checks the dimension of all the annotated fields, and returns all the mismatches.
+    fn check_dimensions(&self) -> Result<(), Vec<FieldDimensionError>>;
+}
+
+#[cfg(feature = "derive")]
+pub use rust_units_derive::CheckDimensions;
+
+/// Error returned by [`CheckDimensions::check_dimensions`] for each mismatching field.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FieldDimensionError {
+    /// The name of the field, or its index in a tuple struct.
+    pub field: &'static str,
+    /// The mismatch.
+    pub error: DimensionError,
+}
+
+impl FieldDimensionError {
+    /// Checks the dimension of a field, used by the derived [`CheckDimensions`] implementations.
+    #[doc(hidden)]
+    pub fn check<F: RuntimeDimensionOf + ?Sized>(
+        errors: &mut Vec<FieldDimensionError>,
+        field: &'static str,
+        value: &F,
+        expected: RuntimeDimension,
+    ) {
+        if let Err(error) = value.runtime_dimension_of().check(expected) {
+            errors.push(FieldDimensionError { field, error });
+        }
+    }
+}
+
+impl Display for FieldDimensionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "field '{}': {}", self.field, self.error)
+    }
+}
+
+impl std::error::Error for FieldDimensionError {}
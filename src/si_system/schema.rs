@@ -0,0 +1,59 @@
+//! [`schemars`] support for the [`Quantity`]s of the SI system.
+//!
+//! Only available with the `schemars` feature (which enables the `serde` feature).
+//!
+//! The schema describes the human readable representation of the [`serialization`](super::serialization) module:
+//! a `{value, unit}` object, where `unit` is the constant work unit of the dimension.
+//!
+//! ```
+//! use rust_units::Quantity;
+//! use rust_units::si_system::dimensions::*;
+//!
+//! let schema = serde_json::to_value(schemars::schema_for!(Quantity<f64, Force>)).unwrap();
+//!
+//! assert_eq!(schema["type"], "object");
+//! assert_eq!(schema["properties"]["value"]["type"], "number");
+//! assert_eq!(schema["properties"]["unit"]["const"], "s^-2.m.kg");
+//! assert_eq!(schema["required"], serde_json::json!(["unit", "value"]));
+//! ```
+
+use schemars::{
+    gen::SchemaGenerator,
+    schema::{InstanceType, Metadata, Schema, SchemaObject},
+    JsonSchema,
+};
+
+use crate::{si_system::runtime::ToRuntimeDimension, Quantity};
+
+impl<T: JsonSchema, D: ToRuntimeDimension> JsonSchema for Quantity<T, D> {
+    fn schema_name() -> String {
+        format!("Quantity_{}_{}", T::schema_name(), D::runtime_dimension())
+    }
+
+    fn is_referenceable() -> bool {
+        false
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        let unit = SchemaObject {
+            instance_type: Some(InstanceType::String.into()),
+            const_value: Some(D::runtime_dimension().to_string().into()),
+            ..Default::default()
+        };
+
+        let mut schema = SchemaObject {
+            instance_type: Some(InstanceType::Object.into()),
+            metadata: Some(Box::new(Metadata {
+                description: Some("A quantity, with its value in work unit.".to_string()),
+                ..Default::default()
+            })),
+            ..Default::default()
+        };
+        let object = schema.object();
+        object.properties.insert("value".to_string(), gen.subschema_for::<T>());
+        object.properties.insert("unit".to_string(), unit.into());
+        object.required.insert("value".to_string());
+        object.required.insert("unit".to_string());
+        schema.into()
+    }
+}
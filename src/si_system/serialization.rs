@@ -0,0 +1,79 @@
+//! [`serde`] support for the [`Quantity`]s of the SI system.
+//!
+//! Only available with the `serde` feature.
+//!
+//! The value is serialized in work unit, along with the dimension, which is checked when deserializing.
+//! The representation depends on [`Serializer::is_human_readable`]:
+//!
+//! - Human readable formats (such as JSON) use a `{value, unit}` object,
+//!   where `unit` is the work unit written as in the [`Display`](std::fmt::Display) implementation of [`Quantity`].
+//! - Compact formats (such as bincode) use a `(value, exponents)` tuple,
+//!   with the exponents of the [`RuntimeDimension`].
+//!
+//! ```
+//! use rust_units::{Quantity, Dimension};
+//! use rust_units::si_system::dimensions::*;
+//!
+//! let force = Force::from_work(9.5);
+//!
+//! let json = serde_json::to_string(&force).unwrap();
+//! assert_eq!(json, r#"{"value":9.5,"unit":"s^-2.m.kg"}"#);
+//! assert_eq!(serde_json::from_str::<Quantity<f64, Force>>(&json).unwrap(), force);
+//!
+//! let bytes = bincode::serialize(&force).unwrap();
+//! assert_eq!(bytes.len(), 8 + 9); // The f64 and the 9 exponents.
+//! assert_eq!(bincode::deserialize::<Quantity<f64, Force>>(&bytes).unwrap(), force);
+//!
+//! // The dimension is checked in both representations.
+//! let error = serde_json::from_str::<Quantity<f64, Energy>>(&json).unwrap_err();
+//! assert!(error.to_string().starts_with("dimension mismatch"));
+//! assert!(bincode::deserialize::<Quantity<f64, Energy>>(&bytes).is_err());
+//! ```
+//!
+//! The dimension is written with its work unit only, so a quantity serialized in the human readable format
+//! can be deserialized with any [`ToRuntimeDimension`] type of the same dimension, such as an alias.
+
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{
+    si_system::runtime::{RuntimeDimension, SIBaseDimension, ToRuntimeDimension},
+    Quantity,
+};
+
+/// Human readable representation.
+#[derive(Serialize, Deserialize)]
+struct ValueWithUnit<V> {
+    value: V,
+    unit: String,
+}
+
+impl<T: Serialize, D: ToRuntimeDimension> Serialize for Quantity<T, D> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            ValueWithUnit {
+                value: self.get_ref_work(),
+                unit: D::runtime_dimension().to_string(),
+            }
+            .serialize(serializer)
+        } else {
+            (self.get_ref_work(), D::runtime_dimension().exponents()).serialize(serializer)
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de>, D: ToRuntimeDimension> Deserialize<'de> for Quantity<T, D> {
+    fn deserialize<De: Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error> {
+        let (value, dimension) = if deserializer.is_human_readable() {
+            let ValueWithUnit { value, unit } = ValueWithUnit::<T>::deserialize(deserializer)?;
+            (value, unit.parse::<RuntimeDimension>().map_err(De::Error::custom)?)
+        } else {
+            let (value, exponents) = <(T, [i8; SIBaseDimension::COUNT])>::deserialize(deserializer)?;
+            (value, RuntimeDimension::from_exponents(exponents))
+        };
+
+        dimension
+            .check(D::runtime_dimension())
+            .map_err(De::Error::custom)?;
+        Ok(Quantity::from_work(value))
+    }
+}
@@ -0,0 +1,62 @@
+//! Fast Fourier transforms of series of [`Quantity`]s, computed with [`rustfft`].
+//!
+//! Only available with the `rustfft` feature.
+
+use rustfft::{num_complex::Complex, FftPlanner};
+
+use crate::{
+    si_system::dimensions::{Frequency, Time},
+    Dimension, Quantity,
+};
+
+/// Computes the spectrum of samples taken every `interval`, and returns its bins paired with their frequencies.
+///
+/// As the samples are real, only the bins of non negative frequencies are returned: with `n` samples,
+/// the `n / 2 + 1` bins of frequencies `k / (n * interval)`, from zero up to the Nyquist frequency.
+///
+/// The bins are not normalized (it is the plain discrete Fourier transform), and keep the dimension of the samples.
+///
+/// ```
+/// use rust_units::{Quantity, Unit};
+/// use rust_units::si_system::{spectrum::spectrum, units::*};
+/// use std::f64::consts::PI;
+///
+/// // A 2 Hz tone sampled at 8 Hz.
+/// let samples: Vec<_> = (0..8)
+///     .map(|n| VOLT.build((2.0 * PI * 2.0 * n as f64 / 8.0).cos()))
+///     .collect();
+///
+/// let bins = spectrum(&samples, SECOND.build(0.125));
+/// assert_eq!(bins.len(), 5);
+///
+/// let (peak, amplitude) = bins
+///     .iter()
+///     .max_by(|(_, a), (_, b)| a.get_ref_work().norm().total_cmp(&b.get_ref_work().norm()))
+///     .unwrap();
+///
+/// assert_eq!(*peak, HERTZ.build(2.0));
+/// assert!((amplitude.get_ref_work().norm() - 4.0).abs() < 1e-12);
+/// ```
+pub fn spectrum<D: Dimension>(
+    samples: &[Quantity<f64, D>],
+    interval: Quantity<f64, Time>,
+) -> Vec<(Quantity<f64, Frequency>, Quantity<Complex<f64>, D>)> {
+    let n = samples.len();
+    if n == 0 {
+        return Vec::new();
+    }
+
+    let mut buffer: Vec<_> = samples
+        .iter()
+        .map(|q| Complex::new(q.get_work_copy(), 0.0))
+        .collect();
+    FftPlanner::new().plan_fft_forward(n).process(&mut buffer);
+
+    let duration = n as f64 * interval.get_work();
+    buffer
+        .into_iter()
+        .take(n / 2 + 1)
+        .enumerate()
+        .map(|(k, bin)| (Quantity::from_work(k as f64 / duration), Quantity::from_work(bin)))
+        .collect()
+}
@@ -0,0 +1,262 @@
+//! Absolute temperatures and temperature differences.
+//!
+//! A [`Quantity`] of dimension [`Temperature`] is a temperature *difference*: it can be scaled, added, ...
+//! An [`AbsoluteTemperature`] is a point on the temperature scale, measured from the absolute zero.
+//! Only the operations that make sense in this affine model are implemented:
+//!
+//! - `AbsoluteTemperature ± TemperatureDifference -> AbsoluteTemperature`
+//! - `TemperatureDifference + AbsoluteTemperature -> AbsoluteTemperature`
+//! - `AbsoluteTemperature - AbsoluteTemperature -> TemperatureDifference`
+//! - `TemperatureDifference * Dimensionless -> TemperatureDifference` (as for any [`Quantity`])
+//!
+//! ```
+//! use rust_units::{Quantity, Unit};
+//! use rust_units::si_system::{dimless, temperature::*, units::*};
+//!
+//! // Heating a body at 293 K by 2 * 15 K.
+//! let initial = AbsoluteTemperature::build_in(293.0, &KELVIN);
+//! let heating: TemperatureDifference<f64> = KELVIN.build(15.0) * dimless(2.0);
+//!
+//! let heated = initial + heating;
+//!
+//! assert_eq!(heated, AbsoluteTemperature::build_in(323.0, &KELVIN));
+//! assert_eq!(heated - initial, KELVIN.build(30.0));
+//! assert_eq!(heated - heating, initial);
+//! ```
+//!
+//! Adding two absolute temperatures, or scaling one, does not compile:
+//!
+//! ```compile_fail
+//! use rust_units::si_system::{temperature::*, units::*};
+//!
+//! let t = AbsoluteTemperature::build_in(293.0, &KELVIN);
+//! let _ = t + t;
+//! ```
+//!
+//! ```compile_fail
+//! use rust_units::si_system::{dimless, temperature::*, units::*};
+//!
+//! let t = AbsoluteTemperature::build_in(293.0, &KELVIN);
+//! let _ = t * dimless(2.0);
+//! ```
+//!
+//! Temperature scales whose zero is not the absolute zero, such as the [`CELSIUS`] and the [`FAHRENHEIT`],
+//! are [`AffineUnit`]s. They build and read [`AbsoluteTemperature`]s, so the difference of two readings
+//! is a [`TemperatureDifference`]:
+//!
+//! ```
+//! use rust_units::Unit;
+//! use rust_units::si_system::{temperature::*, units::*};
+//!
+//! assert_eq!(CELSIUS.build(20.0) - CELSIUS.build(10.0), KELVIN.build(10.0));
+//! assert!((FAHRENHEIT.get(CELSIUS.build(100.0)) - 212.0).abs() < 1e-12);
+//! ```
+
+use core::{
+    fmt::Display,
+    ops::{Add, AddAssign, Div, Mul, Sub, SubAssign},
+};
+
+use derive_where::derive_where;
+
+use crate::{si_system::dimensions::Temperature, Quantity, Unit};
+
+/// A temperature difference. It is a plain [`Quantity`] of dimension [`Temperature`].
+pub type TemperatureDifference<T> = Quantity<T, Temperature>;
+
+/// A temperature measured from the absolute zero.
+///
+/// See the [module documentation](self) for the available operations.
+#[derive_where(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash; T)]
+pub struct AbsoluteTemperature<T> {
+    from_absolute_zero: TemperatureDifference<T>,
+}
+
+impl<T> AbsoluteTemperature<T> {
+    /// Creates a new absolute temperature from its difference with the absolute zero.
+    pub const fn from_absolute_zero(difference: TemperatureDifference<T>) -> Self {
+        Self {
+            from_absolute_zero: difference,
+        }
+    }
+
+    /// Returns the difference between this temperature and the absolute zero.
+    pub fn get_from_absolute_zero(self) -> TemperatureDifference<T> {
+        self.from_absolute_zero
+    }
+
+    /// Creates a new absolute temperature from its value in the work unit (kelvin).
+    pub const fn from_work(value: T) -> Self {
+        Self::from_absolute_zero(Quantity::from_work(value))
+    }
+
+    /// Returns the value of the temperature in the work unit (kelvin).
+    pub fn get_work(self) -> T {
+        self.from_absolute_zero.get_work()
+    }
+
+    /// Creates a new absolute temperature from its value in the given unit,
+    /// whose zero must be the absolute zero (such as kelvin).
+    pub fn build_in<U: Unit<T, Dimension = Temperature>>(value: T, unit: &U) -> Self {
+        Self::from_absolute_zero(unit.build(value))
+    }
+
+    /// Returns the value in the given unit, whose zero must be the absolute zero (such as kelvin).
+    pub fn get_in<U: Unit<T, Dimension = Temperature>>(self, unit: &U) -> T {
+        unit.get(self.from_absolute_zero)
+    }
+}
+
+/// A temperature unit whose zero is not necessarily the absolute zero.
+///
+/// A value `v` in this unit is the absolute temperature `v * scale + offset` in the work unit (kelvin).
+/// The [`scale`](AffineUnit::scale) alone converts the temperature differences,
+/// see [`build_difference`](AffineUnit::build_difference).
+pub trait AffineUnit<T> {
+    /// The size of one degree of the unit, in the work unit (kelvin).
+    fn scale(&self) -> T;
+
+    /// The zero of the unit, in the work unit (kelvin).
+    fn offset(&self) -> T;
+
+    /// Converts a value into an [`AbsoluteTemperature`].
+    fn build(&self, value: T) -> AbsoluteTemperature<T>
+    where
+        T: Mul<Output = T> + Add<Output = T>,
+    {
+        AbsoluteTemperature::from_work(value * self.scale() + self.offset())
+    }
+
+    /// Retrieves the value of an [`AbsoluteTemperature`].
+    fn get(&self, temperature: AbsoluteTemperature<T>) -> T
+    where
+        T: Sub<Output = T> + Div<Output = T>,
+    {
+        (temperature.get_work() - self.offset()) / self.scale()
+    }
+
+    /// Converts a value into a [`TemperatureDifference`], ignoring the offset.
+    ///
+    /// ```
+    /// use rust_units::Unit;
+    /// use rust_units::si_system::{temperature::*, units::*};
+    ///
+    /// assert_eq!(CELSIUS.build_difference(5.0), KELVIN.build(5.0));
+    /// assert_eq!(FAHRENHEIT.build_difference(9.0), KELVIN.build(5.0));
+    /// ```
+    fn build_difference(&self, value: T) -> TemperatureDifference<T>
+    where
+        T: Mul<Output = T>,
+    {
+        Quantity::from_work(value * self.scale())
+    }
+
+    /// Retrieves the value of a [`TemperatureDifference`], ignoring the offset.
+    fn get_difference(&self, difference: TemperatureDifference<T>) -> T
+    where
+        T: Div<Output = T>,
+    {
+        difference.get_work() / self.scale()
+    }
+}
+
+/// A struct for an [`AffineUnit`], storing its scale and offset.
+#[derive_where(Debug, Default, Clone, Copy, PartialEq, Eq, Hash; T)]
+pub struct AffineUnitStruct<T> {
+    scale: T,
+    offset: T,
+}
+
+impl<T> AffineUnitStruct<T> {
+    /// Creates a new [`AffineUnitStruct`] from the size of one degree and the zero of the unit, in kelvin.
+    ///
+    /// With a zero scale, all the values of the unit would be the same temperature (the offset),
+    /// and [`get`](AffineUnit::get) would divide by zero.
+    pub const fn new(scale: T, offset: T) -> Self {
+        Self { scale, offset }
+    }
+}
+
+impl<T: Clone> AffineUnit<T> for AffineUnitStruct<T> {
+    fn scale(&self) -> T {
+        self.scale.clone()
+    }
+
+    fn offset(&self) -> T {
+        self.offset.clone()
+    }
+}
+
+/// The degree Celsius: same degree as the kelvin, with 0 °C = 273.15 K.
+pub const CELSIUS: AffineUnitStruct<f64> = AffineUnitStruct::new(1.0, 273.15);
+
+/// The degree Fahrenheit: one degree is 5/9 K, with 32 °F = 0 °C.
+pub const FAHRENHEIT: AffineUnitStruct<f64> = AffineUnitStruct::new(5.0 / 9.0, 273.15 - 32.0 * 5.0 / 9.0);
+
+impl<Tl, Tr> Add<TemperatureDifference<Tr>> for AbsoluteTemperature<Tl>
+where
+    Tl: Add<Tr>,
+{
+    type Output = AbsoluteTemperature<Tl::Output>;
+
+    fn add(self, rhs: TemperatureDifference<Tr>) -> Self::Output {
+        AbsoluteTemperature::from_work(self.get_work() + rhs.get_work())
+    }
+}
+
+impl<Tl, Tr> Add<AbsoluteTemperature<Tr>> for TemperatureDifference<Tl>
+where
+    Tl: Add<Tr>,
+{
+    type Output = AbsoluteTemperature<Tl::Output>;
+
+    fn add(self, rhs: AbsoluteTemperature<Tr>) -> Self::Output {
+        AbsoluteTemperature::from_work(self.get_work() + rhs.get_work())
+    }
+}
+
+impl<Tl, Tr> AddAssign<TemperatureDifference<Tr>> for AbsoluteTemperature<Tl>
+where
+    Tl: AddAssign<Tr>,
+{
+    fn add_assign(&mut self, rhs: TemperatureDifference<Tr>) {
+        *self.from_absolute_zero.get_mut_work() += rhs.get_work()
+    }
+}
+
+impl<Tl, Tr> Sub<TemperatureDifference<Tr>> for AbsoluteTemperature<Tl>
+where
+    Tl: Sub<Tr>,
+{
+    type Output = AbsoluteTemperature<Tl::Output>;
+
+    fn sub(self, rhs: TemperatureDifference<Tr>) -> Self::Output {
+        AbsoluteTemperature::from_work(self.get_work() - rhs.get_work())
+    }
+}
+
+impl<Tl, Tr> SubAssign<TemperatureDifference<Tr>> for AbsoluteTemperature<Tl>
+where
+    Tl: SubAssign<Tr>,
+{
+    fn sub_assign(&mut self, rhs: TemperatureDifference<Tr>) {
+        *self.from_absolute_zero.get_mut_work() -= rhs.get_work()
+    }
+}
+
+impl<Tl, Tr> Sub<AbsoluteTemperature<Tr>> for AbsoluteTemperature<Tl>
+where
+    Tl: Sub<Tr>,
+{
+    type Output = TemperatureDifference<Tl::Output>;
+
+    fn sub(self, rhs: AbsoluteTemperature<Tr>) -> Self::Output {
+        Quantity::from_work(self.get_work() - rhs.get_work())
+    }
+}
+
+impl<T: Display> Display for AbsoluteTemperature<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.from_absolute_zero.fmt(f)
+    }
+}
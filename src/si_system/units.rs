@@ -2,7 +2,7 @@
 //!
 //! These units are computed during the compilation, and implement mainly [`TypeUnit`].
 
-use std::{
+use core::{
     fmt::Display,
     marker::PhantomData,
     ops::{Div, Mul},
@@ -13,7 +13,7 @@ use extended_typenum::{Integer, Pow, Rational, Z0};
 use num_traits::Inv;
 
 use crate::{
-    Dimension, TypeUnit, impl_type_unit, si_system::units::{
+    Dimension, TypeUnit, UnitSymbol, impl_type_unit, si_system::units::{
         impl_helpers::{GetSITypePropUnitData, ToSITypePropUnitData},
         inner_unit_types::{DivUnits, InvUnit, IsSimple, MulCUnit, MulCUnitExtended, MulUnits, PowerUnit, PrefixedUnit, SimpleUnit},
         prefix::{CanChangePrefix, TypePrefix},
@@ -327,11 +327,20 @@ impl_type_unit! {
     }
 }
 
+impl<I> UnitSymbol for SIPropUnit<I>
+where
+    I: UnitSymbol,
+{
+    fn symbol(&self) -> &'static str {
+        self.inner.symbol()
+    }
+}
+
 impl<I> Display for SIPropUnit<I>
 where
     I: Display,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         self.inner.fmt(f)
     }
 }
@@ -1,11 +1,12 @@
 //! Contains structs and traits that help with the implementation of SI units.
 
-use std::{f64::consts::PI, ops::{Div, Mul, Rem, Sub}};
+use core::{f64::consts::PI, ops::{Div, Mul, Rem, Sub}};
 
 use extended_typenum::{
     Diff, Eq, False, Integer, IsEqual, Mod, NInt, NonZero, PInt, Quot, Rational, True, UInt,
     Unsigned, R, U0, U1, U10, Z0,
 };
+use num_traits::float::FloatCore;
 
 use super::*;
 use crate::{impl_type_unit, Dimension, Quantity};
@@ -256,11 +257,11 @@ impl_type_unit! {
 impl_type_unit! {
     impl{T: Mul<f64, Output = T> + Div<f64, Output = T>, D: Dimension, F: Rational, E: Integer, PiE: Integer} TypeUnit<T> for UnitHelper<D, F, E, PiE, False> => D {
         fn t_build(value) {
-            Quantity::from_work(value * (F::F64 * 10f64.powi(E::I32) * PI.powi(PiE::I32)))
+            Quantity::from_work(value * (F::F64 * FloatCore::powi(10f64, E::I32) * FloatCore::powi(PI, PiE::I32)))
         }
 
         fn t_get(quantity) {
-            quantity.get_work() / (F::F64 * 10f64.powi(E::I32) * PI.powi(PiE::I32))
+            quantity.get_work() / (F::F64 * FloatCore::powi(10f64, E::I32) * FloatCore::powi(PI, PiE::I32))
         }
     }
 }
@@ -2,7 +2,7 @@
 //!
 //! You don't directly build these types. Instead, you build [`SIPropUnit`]s directly.
 
-use std::{
+use core::{
     fmt::Display,
     marker::PhantomData,
     ops::{Add, Div, Mul, Neg, Sub},
@@ -20,7 +20,7 @@ use crate::{
         prefix::{self, CanChangePrefix, TypePrefix},
         SIPropUnit, SimpleSIPropUnitExtended,
     },
-    Dimension,
+    Dimension, UnitSymbol,
 };
 
 // --------------------------------------------------
@@ -56,13 +56,13 @@ pub struct WithIsSimpleStruct<T, R> {
 pub type WithIsSimple<T> = WithIsSimpleStruct<T, <T as IsSimple>::Result>;
 
 impl<T: Display> Display for WithIsSimpleStruct<T, True> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         self.val.fmt(f)
     }
 }
 
 impl<T: Display> Display for WithIsSimpleStruct<T, False> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "(")?;
         self.val.fmt(f)?;
         write!(f, ")")
@@ -164,11 +164,17 @@ impl<D: Dimension, F, E, PiE, Meta> Display for SimpleUnit<D, F, E, PiE, Meta>
 where
     Meta: Display,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         self.meta.fmt(f)
     }
 }
 
+impl<D: Dimension, F, E, PiE> UnitSymbol for SimpleUnit<D, F, E, PiE, &'static str> {
+    fn symbol(&self) -> &'static str {
+        self.meta
+    }
+}
+
 impl<D: Dimension, F, E, PiE, Meta> ToSITypePropUnitData for SimpleUnit<D, F, E, PiE, Meta> {
     type D = D;
     type F = F;
@@ -263,7 +269,7 @@ where
     Self: DecomposePrefix,
     <Self as DecomposePrefix>::Base: Display,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{}", P::SYMBOL)?;
         self.get_ref_base().fmt(f)
     }
@@ -314,7 +320,7 @@ where
     U1: Display,
     U2: Display,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         self.unit_1.fmt(f)?;
         write!(f, ".")?;
         self.unit_2.fmt(f)
@@ -368,7 +374,7 @@ where
     U2: IsSimple,
     for<'a> WithIsSimple<&'a U2>: Display,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         self.unit_1.fmt(f)?;
         write!(f, "/")?;
         WithIsSimpleStruct {
@@ -422,7 +428,7 @@ where
     U: IsSimple,
     for<'a> WithIsSimple<&'a U>: Display,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "1/")?;
         WithIsSimpleStruct {
             val: &self.unit,
@@ -477,7 +483,7 @@ where
     U: IsSimple,
     for<'a> WithIsSimple<&'a U>: Display,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         WithIsSimpleStruct {
             val: &self.unit,
             res: PhantomData,
@@ -540,7 +546,7 @@ where
     U: IsSimple,
     for<'a> WithIsSimple<&'a U>: Display,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         F::F64.fmt(f)?;
         let e = E::I64;
         if e != 0 {
@@ -601,7 +607,7 @@ where
     U: IsSimple,
     for<'a> WithIsSimple<&'a U>: Display,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         F::F64.fmt(f)?;
         let pi_e = PiE::I64;
         if pi_e != 0 {
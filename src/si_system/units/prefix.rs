@@ -3,8 +3,13 @@
 //! They are defined both as individual types and as an enum.
 //! This enables both compile time and runtime management of the prefixes.
 
+#[cfg(feature = "alloc")]
+use alloc::{format, string::String};
+
 use extended_typenum::{consts::*, Integer};
 
+use crate::{Unit, WorkPropUnit};
+
 /// The prefix of SI units.
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub enum SIPrefix {
@@ -96,7 +101,27 @@ impl SIPrefix {
 
     /// Returns the decimal multiplier as an `f64` (e.g. `Kilo` -> 1000.0).
     pub fn value_f64(self) -> f64 {
-        10f64.powi(self.power())
+        num_traits::float::FloatCore::powi(10f64, self.power())
+    }
+
+    /// Returns `unit` multiplied by the prefix, for prefixes only known at runtime
+    /// (for example read from a configuration file).
+    ///
+    /// For a prefix known at compile time, the `set_*_prefix` methods of the [`SIPropUnit`](super::SIPropUnit)s
+    /// keep the prefix in the type. The requirements on `unit` are the ones of [`WorkPropUnit::from_unit`].
+    ///
+    /// ```
+    /// use rust_units::Unit;
+    /// use rust_units::si_system::units::{prefix::SIPrefix, *};
+    ///
+    /// let kilometer = SIPrefix::Kilo.apply(&METER);
+    ///
+    /// assert_eq!(kilometer.build(2.5), KILOMETER.build(2.5));
+    /// assert_eq!(kilometer.get(METER.build(500.0)), KILOMETER.get(METER.build(500.0)));
+    /// assert_eq!(SIPrefix::Milli.apply(&METER).build(1.0), METER.build(0.001));
+    /// ```
+    pub fn apply<U: Unit<f64>>(self, unit: &U) -> WorkPropUnit<f64, U::Dimension> {
+        WorkPropUnit::from_work_per_unit(WorkPropUnit::from_unit(unit).work_per_unit() * self.value_f64())
     }
 
     /// Returns the symbol for this prefix (e.g. `h` for `Hecto`).
@@ -132,6 +157,7 @@ impl SIPrefix {
 
     /// Try to build a [`SIPrefix`] from an integer power of ten.
     /// Returns an error if no prefix corresponds to the provided power (for example 0).
+    #[cfg(feature = "alloc")]
     pub fn from_power(power: i32) -> Result<SIPrefix, ParseSIPrefixError> {
         match power {
             -30 => Ok(SIPrefix::Quecto),
@@ -167,6 +193,7 @@ impl SIPrefix {
     }
 
     /// Try to build a [`SIPrefix`] from a symbol string (e.g. "k", "M", "da", "µ").
+    #[cfg(feature = "alloc")]
     pub fn from_symbol(s: &str) -> Result<SIPrefix, ParseSIPrefixError> {
         let s = s.trim();
 
@@ -202,6 +229,7 @@ impl SIPrefix {
     }
 
     /// Try to build a [`SIPrefix`] from a name (e.g. "kilo", "Mega").
+    #[cfg(feature = "alloc")]
     pub fn from_name(s: &str) -> Result<SIPrefix, ParseSIPrefixError> {
         match s.to_ascii_lowercase().as_str() {
             "quetta" => Ok(SIPrefix::Quetta),
@@ -235,22 +263,25 @@ impl SIPrefix {
 }
 
 /// Error type for [`SIPrefix`] parsing/construction functions.
+#[cfg(feature = "alloc")]
 #[derive(Debug, Clone)]
 pub struct ParseSIPrefixError(pub String);
 
-impl std::fmt::Display for ParseSIPrefixError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+#[cfg(feature = "alloc")]
+impl core::fmt::Display for ParseSIPrefixError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         self.0.fmt(f)
     }
 }
 
-impl std::fmt::Display for SIPrefix {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for SIPrefix {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         self.symbol().fmt(f)
     }
 }
 
-impl std::str::FromStr for SIPrefix {
+#[cfg(feature = "alloc")]
+impl core::str::FromStr for SIPrefix {
     type Err = ParseSIPrefixError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
@@ -311,8 +342,8 @@ macro_rules! define_prefix_type {
             const PREFIX: SIPrefix = SIPrefix::$name;
         }
 
-        impl std::fmt::Display for $name {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        impl core::fmt::Display for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
                 Self::PREFIX.fmt(f)
             }
         }
@@ -484,8 +515,8 @@ impl TypePrefix for NotPrefixable {
     const SYMBOL: &str = "";
     const PREFIX: SIPrefix = SIPrefix::None;
 }
-impl std::fmt::Display for NotPrefixable {
-    fn fmt(&self,f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for NotPrefixable {
+    fn fmt(&self,f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         Self::PREFIX.fmt(f)
     }
 }
@@ -216,6 +216,60 @@ pub const SIEVERT: SimpleSIPropUnit<EquivalentDose, rational!(P1), Z0, &str> =
 pub const KATAL: SimpleSIPropUnit<CatalyticActivity, rational!(P1), Z0, &str> =
     MOLE.per(SECOND).redefine_as("kat");
 
+// ------------------ Common prefixed SI units ------------------
+
+/// Kilometer (length)
+///
+/// 1 [`KILOMETER`] = 1000 [`METER`]
+///
+/// ```
+/// use rust_units::Unit;
+/// use rust_units::si_system::units::*;
+///
+/// assert_eq!(KILOMETER.build(5.0), METER.build(5000.0));
+/// assert!((KILOMETER.get(KILOMETER.build(0.3)) - 0.3).abs() < f64::EPSILON);
+/// ```
+pub const KILOMETER: SimplePrefixedSIPropUnit<Length, rational!(P1), Z0, Kilo, &str> = METER.set_kilo_prefix();
+
+/// Centimeter (length)
+///
+/// 100 [`CENTIMETER`] = 1 [`METER`]
+///
+/// ```
+/// use rust_units::Unit;
+/// use rust_units::si_system::units::*;
+///
+/// assert_eq!(CENTIMETER.build(250.0), METER.build(2.5));
+/// assert!((CENTIMETER.get(CENTIMETER.build(0.3)) - 0.3).abs() < f64::EPSILON);
+/// ```
+pub const CENTIMETER: SimplePrefixedSIPropUnit<Length, rational!(P1), Z0, Centi, &str> = METER.set_centi_prefix();
+
+/// Millimeter (length)
+///
+/// 1000 [`MILLIMETER`] = 1 [`METER`]
+///
+/// ```
+/// use rust_units::Unit;
+/// use rust_units::si_system::units::*;
+///
+/// assert_eq!(MILLIMETER.build(2500.0), METER.build(2.5));
+/// assert!((MILLIMETER.get(MILLIMETER.build(0.3)) - 0.3).abs() < f64::EPSILON);
+/// ```
+pub const MILLIMETER: SimplePrefixedSIPropUnit<Length, rational!(P1), Z0, Milli, &str> = METER.set_milli_prefix();
+
+/// Gram (mass)
+///
+/// 1000 [`GRAM`] = 1 [`KILOGRAM`]
+///
+/// ```
+/// use rust_units::Unit;
+/// use rust_units::si_system::units::*;
+///
+/// assert_eq!(GRAM.build(2500.0), KILOGRAM.build(2.5));
+/// assert!((GRAM.get(GRAM.build(0.3)) - 0.3).abs() < f64::EPSILON);
+/// ```
+pub const GRAM: SimpleSIPropUnit<Mass, rational!(P1), N3, &str> = SIPropUnit::new("g");
+
 // ------------------ Non SI units ------------------
 
 // Acceleration
@@ -233,6 +287,7 @@ pub const KATAL: SimpleSIPropUnit<CatalyticActivity, rational!(P1), Z0, &str> =
 /// let g = STANDARD_GRAVITY.build(1.0);
 ///
 /// assert_eq!(g.get_in(&si_unit), 9.80665);
+/// assert!((STANDARD_GRAVITY.get(STANDARD_GRAVITY.build(0.3)) - 0.3).abs() < f64::EPSILON);
 /// ```
 pub const STANDARD_GRAVITY: SimplePrefixedSIPropUnit<
     Acceleration,
@@ -262,6 +317,7 @@ type StandardGravityConst = rational!(op!(P9 * P100000 + P8 * P10000 + P665); P1
 /// let gal = GAL.build(1.0);
 ///
 /// assert_eq!(gal.get_in(&si_unit), 0.01);
+/// assert!((GAL.get(GAL.build(0.3)) - 0.3).abs() < f64::EPSILON);
 /// ```
 pub const GAL: SimpleSIPropUnit<Acceleration, rational!(P1), N2, &str> = METER
     .set_centi_prefix()
@@ -279,7 +335,8 @@ pub const GAL: SimpleSIPropUnit<Acceleration, rational!(P1), N2, &str> = METER
 /// use rust_units::si_system::units::*;
 /// use std::f64::consts::PI;
 ///
-/// assert_eq!(DEGREE.build(360.0), RADIAN.build(2.0*PI))
+/// assert_eq!(DEGREE.build(360.0), RADIAN.build(2.0*PI));
+/// assert!((DEGREE.get(DEGREE.build(0.3)) - 0.3).abs() < f64::EPSILON);
 /// ```
 pub const DEGREE: SimpleSIPropUnitExtended<Angle, rational!(P1, U18), N1, P1, &str> = RADIAN
     .c_times_extended::<rational!(P1, U18), N1, P1>()
@@ -293,7 +350,8 @@ pub const DEGREE: SimpleSIPropUnitExtended<Angle, rational!(P1, U18), N1, P1, &s
 /// use rust_units::{Unit, float::*};
 /// use rust_units::si_system::units::*;
 ///
-/// assert!((GON.build(100.0) - DEGREE.build(90.0)).abs() < RADIAN.build(f64::EPSILON * 4.0))
+/// assert!((GON.build(100.0) - DEGREE.build(90.0)).abs() < RADIAN.build(f64::EPSILON * 4.0));
+/// assert!((GON.get(GON.build(0.3)) - 0.3).abs() < f64::EPSILON);
 /// ```
 pub const GON: SimpleSIPropUnitExtended<Angle, rational!(P9, U18), N2, P1, &str> =
     DEGREE.c_times::<rational!(P9), N1>().redefine_as("gon");
@@ -306,7 +364,8 @@ pub const GON: SimpleSIPropUnitExtended<Angle, rational!(P9, U18), N2, P1, &str>
 /// use rust_units::{Unit, float::*};
 /// use rust_units::si_system::units::*;
 ///
-/// assert!((MINUTE_ARC.build(60.0) - DEGREE.build(1.0)).abs() < RADIAN.build(f64::EPSILON * 4.0))
+/// assert!((MINUTE_ARC.build(60.0) - DEGREE.build(1.0)).abs() < RADIAN.build(f64::EPSILON * 4.0));
+/// assert!((MINUTE_ARC.get(MINUTE_ARC.build(0.3)) - 0.3).abs() < f64::EPSILON);
 /// ```
 pub const MINUTE_ARC: SimplePrefixedSIPropUnitExtended<
     Angle,
@@ -325,7 +384,8 @@ pub const MINUTE_ARC: SimplePrefixedSIPropUnitExtended<
 /// use rust_units::{Unit, float::*};
 /// use rust_units::si_system::units::*;
 ///
-/// assert!((SECOND_ARC.build(60.0) - MINUTE_ARC.build(1.0)).abs() < RADIAN.build(f64::EPSILON * 4.0))
+/// assert!((SECOND_ARC.build(60.0) - MINUTE_ARC.build(1.0)).abs() < RADIAN.build(f64::EPSILON * 4.0));
+/// assert!((SECOND_ARC.get(SECOND_ARC.build(0.3)) - 0.3).abs() < f64::EPSILON);
 /// ```
 pub const SECOND_ARC: SimplePrefixedSIPropUnitExtended<
     Angle,
@@ -344,7 +404,8 @@ pub const SECOND_ARC: SimplePrefixedSIPropUnitExtended<
 /// use rust_units::{Unit, float::*};
 /// use rust_units::si_system::units::*;
 ///
-/// assert!((MILLISECOND_ARC.build(60.0) - SECOND_ARC.build(1.0)).abs() < RADIAN.build(f64::EPSILON * 4.0))
+/// assert!((MILLISECOND_ARC.build(60.0) - SECOND_ARC.build(1.0)).abs() < RADIAN.build(f64::EPSILON * 4.0));
+/// assert!((MILLISECOND_ARC.get(MILLISECOND_ARC.build(0.3)) - 0.3).abs() < f64::EPSILON);
 /// ```
 pub const MILLISECOND_ARC: SimplePrefixedSIPropUnitExtended<
     Angle,
@@ -363,7 +424,8 @@ pub const MILLISECOND_ARC: SimplePrefixedSIPropUnitExtended<
 /// use rust_units::{Unit, float::*};
 /// use rust_units::si_system::units::*;
 ///
-/// assert!((MICROSECOND_ARC.build(60.0) - MILLISECOND_ARC.build(1.0)).abs() < RADIAN.build(f64::EPSILON * 4.0))
+/// assert!((MICROSECOND_ARC.build(60.0) - MILLISECOND_ARC.build(1.0)).abs() < RADIAN.build(f64::EPSILON * 4.0));
+/// assert!((MICROSECOND_ARC.get(MICROSECOND_ARC.build(0.3)) - 0.3).abs() < f64::EPSILON);
 /// ```
 pub const MICROSECOND_ARC: SimplePrefixedSIPropUnitExtended<
     Angle,
@@ -383,7 +445,8 @@ pub const MICROSECOND_ARC: SimplePrefixedSIPropUnitExtended<
 /// use rust_units::si_system::units::*;
 /// use std::f64::consts::PI;
 ///
-/// assert!((REVOLUTION.build(1.0) - RADIAN.build(2.0*PI)).abs() < RADIAN.build(f64::EPSILON * 4.0))
+/// assert!((REVOLUTION.build(1.0) - RADIAN.build(2.0*PI)).abs() < RADIAN.build(f64::EPSILON * 4.0));
+/// assert!((REVOLUTION.get(REVOLUTION.build(0.3)) - 0.3).abs() < f64::EPSILON);
 /// ```
 pub const REVOLUTION: SimplePrefixedSIPropUnitExtended<
     Angle,
@@ -394,6 +457,168 @@ pub const REVOLUTION: SimplePrefixedSIPropUnitExtended<
     &str
 > = RADIAN.c_times_extended::<rational!(P2), Z0, P1>().redefine_as("rev").make_not_prefixable();
 
+// Angular velocity
+
+/// Revolutions per minute (angular velocity)
+///
+/// 1 [`RPM`] = 1 [`REVOLUTION`] / 60 [`SECOND`]
+///
+/// ```
+/// use rust_units::{Unit, float::*};
+/// use rust_units::si_system::units::*;
+/// use std::f64::consts::PI;
+///
+/// let rad_per_second = RADIAN.per(SECOND);
+///
+/// assert!((RPM.build(60.0).get_in(&rad_per_second) - 2.0 * PI).abs() < 1e-12);
+/// assert!((RPM.get(RPM.build(0.3)) - 0.3).abs() < f64::EPSILON);
+/// ```
+pub const RPM: SimplePrefixedSIPropUnitExtended<
+    AngularVelocity,
+    rational!(P1, U30),
+    Z0,
+    P1,
+    NotPrefixable,
+    &str
+> = RADIAN.per(SECOND).c_times_extended::<rational!(P1, U30), Z0, P1>().redefine_as("rpm").make_not_prefixable();
+
+// Length
+
+/// Inch (length)
+///
+/// 1 [`INCH`] = 2.54 centi[`METER`]
+///
+/// ```
+/// use rust_units::Unit;
+/// use rust_units::si_system::units::*;
+///
+/// assert!((INCH.build(1.0).get_in(&METER) - 0.0254).abs() < 1e-15);
+/// assert!((INCH.get(INCH.build(0.3)) - 0.3).abs() < f64::EPSILON);
+/// ```
+pub const INCH: SimplePrefixedSIPropUnit<
+    Length,
+    rational!(P254),
+    N4,
+    NotPrefixable,
+    &str
+> = METER.c_times::<rational!(P254), N4>().redefine_as("in").make_not_prefixable();
+
+/// Foot (length)
+///
+/// 1 [`FOOT`] = 12 [`INCH`]
+///
+/// ```
+/// use rust_units::{Unit, float::*};
+/// use rust_units::si_system::units::*;
+///
+/// assert!((FOOT.build(1.0) - INCH.build(12.0)).abs() < METER.build(1e-15));
+/// assert!((FOOT.get(FOOT.build(0.3)) - 0.3).abs() < f64::EPSILON);
+/// ```
+pub const FOOT: SimplePrefixedSIPropUnit<
+    Length,
+    rational!(op!(P254 * P12)),
+    N4,
+    NotPrefixable,
+    &str
+> = INCH.c_times::<rational!(P12), Z0>().redefine_as("ft").make_not_prefixable();
+
+/// Yard (length)
+///
+/// 1 [`YARD`] = 3 [`FOOT`]
+///
+/// ```
+/// use rust_units::{Unit, float::*};
+/// use rust_units::si_system::units::*;
+///
+/// assert!((YARD.build(1.0) - FOOT.build(3.0)).abs() < METER.build(1e-15));
+/// assert!((YARD.get(YARD.build(0.3)) - 0.3).abs() < f64::EPSILON);
+/// ```
+pub const YARD: SimplePrefixedSIPropUnit<
+    Length,
+    rational!(op!(P254 * P12 * P3)),
+    N4,
+    NotPrefixable,
+    &str
+> = FOOT.c_times::<rational!(P3), Z0>().redefine_as("yd").make_not_prefixable();
+
+/// Mile (length)
+///
+/// 1 [`MILE`] = 1760 [`YARD`] = 1609.344 [`METER`]
+///
+/// ```
+/// use rust_units::{Unit, float::*};
+/// use rust_units::si_system::units::*;
+///
+/// assert!((MILE.build(1.0) - METER.build(1609.344)).abs() < METER.build(1e-12));
+/// assert!((MILE.get(MILE.build(0.3)) - 0.3).abs() < f64::EPSILON);
+/// ```
+pub const MILE: SimplePrefixedSIPropUnit<
+    Length,
+    rational!(op!(P254 * P12 * P3 * P16 * P110)),
+    N4,
+    NotPrefixable,
+    &str
+> = YARD.c_times::<rational!(op!(P16 * P110)), Z0>().redefine_as("mi").make_not_prefixable();
+
+// Time
+
+/// Minute (time)
+///
+/// 1 [`MINUTE`] = 60 [`SECOND`]
+///
+/// ```
+/// use rust_units::Unit;
+/// use rust_units::si_system::units::*;
+///
+/// assert_eq!(MINUTE.build(2.5), SECOND.build(150.0));
+/// assert!((MINUTE.get(MINUTE.build(0.3)) - 0.3).abs() < f64::EPSILON);
+/// ```
+pub const MINUTE: SimplePrefixedSIPropUnit<
+    Time,
+    rational!(P6),
+    P1,
+    NotPrefixable,
+    &str
+> = SECOND.c_times::<rational!(P6), P1>().redefine_as("min").make_not_prefixable();
+
+/// Hour (time)
+///
+/// 1 [`HOUR`] = 60 [`MINUTE`] = 3600 [`SECOND`]
+///
+/// ```
+/// use rust_units::Unit;
+/// use rust_units::si_system::units::*;
+///
+/// assert_eq!(HOUR.build(1.5), SECOND.build(5400.0));
+/// assert!((HOUR.get(HOUR.build(0.3)) - 0.3).abs() < f64::EPSILON);
+/// ```
+pub const HOUR: SimplePrefixedSIPropUnit<
+    Time,
+    rational!(P36),
+    P2,
+    NotPrefixable,
+    &str
+> = MINUTE.c_times::<rational!(P6), P1>().redefine_as("h").make_not_prefixable();
+
+/// Day (time)
+///
+/// 1 [`DAY`] = 24 [`HOUR`] = 86400 [`SECOND`]
+///
+/// ```
+/// use rust_units::Unit;
+/// use rust_units::si_system::units::*;
+///
+/// assert_eq!(DAY.build(2.0), SECOND.build(172_800.0));
+/// assert!((DAY.get(DAY.build(0.3)) - 0.3).abs() < f64::EPSILON);
+/// ```
+pub const DAY: SimplePrefixedSIPropUnit<
+    Time,
+    rational!(op!(P36 * P24)),
+    P2,
+    NotPrefixable,
+    &str
+> = HOUR.c_times::<rational!(P24), Z0>().redefine_as("d").make_not_prefixable();
+
 // Area and Second Moment of Area
 // acre (based on U.S. survey foot)	square meter (m2)	4.046 873	E+03
 // are (a)	square meter (m2)	1.0	E+02
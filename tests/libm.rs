@@ -0,0 +1,25 @@
+//! Float math of the quantities with the `libm` backend, without the standard library:
+//!
+//! ```text
+//! cargo test --no-default-features --features libm --test libm
+//! ```
+#![cfg(all(feature = "libm", not(feature = "std")))]
+
+use rust_units::float::{FloatHypot, FloatSqrt};
+use rust_units::si_system::dimensions::{Area, Length};
+use rust_units::Dimension;
+
+#[test]
+fn sqrt() {
+    let side = FloatSqrt::sqrt(Area::from_work(16.0_f64));
+
+    assert_eq!(side, Length::from_work(4.0));
+    assert!(FloatSqrt::sqrt(Area::from_work(-1.0_f64)).get_work().is_nan());
+}
+
+#[test]
+fn hypot() {
+    let diagonal = FloatHypot::hypot(Length::from_work(3.0_f64), Length::from_work(4.0));
+
+    assert!((diagonal - Length::from_work(5.0)).get_work().abs() < 1e-12);
+}